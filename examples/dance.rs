@@ -2,17 +2,12 @@ use bevy::prelude::*;
 use bevy::tasks::futures_lite::StreamExt;
 use bevy::MinimalPlugins;
 use bevy_defer::{
-    access::{deref::AsyncComponentDeref, AsyncComponent, AsyncWorld},
+    access::{deref::AsyncComponentDeref, AsyncComponent, AsyncEntity, AsyncWorld, ByName},
     signal_ids, AccessError, AppReactorExtension, AsyncExtension, AsyncPlugin,
 };
 use futures::FutureExt;
 use ref_cast::RefCast;
-use std::{
-    collections::HashMap,
-    ops::{Deref, DerefMut},
-    pin::pin,
-    time::Duration,
-};
+use std::{pin::pin, time::Duration};
 signal_ids! {
     SigText: &'static str,
 }
@@ -37,23 +32,6 @@ pub enum GameState {
     Animating,
 }
 
-#[derive(Debug, Resource, Default)]
-pub struct NamedEntities(HashMap<String, Entity>);
-
-impl Deref for NamedEntities {
-    type Target = HashMap<String, Entity>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl DerefMut for NamedEntities {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
 impl AsyncComponentDeref for Animator {
     type Target = AsyncAnimator;
 
@@ -106,18 +84,10 @@ pub fn main() {
     app.add_plugins(AsyncPlugin::default_settings());
     app.react_to_state::<GameState>();
 
-    let e1 = app
-        .world_mut()
-        .spawn((HP(0), Animator("Idle".to_owned())))
-        .id();
-    let e2 = app
-        .world_mut()
-        .spawn((HP(0), Animator("Idle".to_owned())))
-        .id();
-    app.insert_resource(NamedEntities(HashMap::from([
-        ("Richard".to_owned(), e1),
-        ("Jen".to_owned(), e2),
-    ])));
+    app.world_mut()
+        .spawn((Name::new("Richard"), HP(0), Animator("Idle".to_owned())));
+    app.world_mut()
+        .spawn((Name::new("Jen"), HP(0), Animator("Idle".to_owned())));
 
     app.spawn_task(async move {
         // This is an `AsyncWorld`.
@@ -141,11 +111,8 @@ pub fn main() {
             .next()
             .await
             .unwrap();
-        // This function is async because we don't own the world,
-        // we send a query request and wait for the response.
-        let richard_entity = world
-            .resource::<NamedEntities>()
-            .get(|res| *res.get("Richard").unwrap())?;
+        // Find the entity by `Name`, this is resolved against the world.
+        let richard_entity = AsyncEntity::from_virtual(ByName("Richard")).try_get_id()?;
         let richard = world.entity(richard_entity);
         // We can also mutate the world asynchronously.
         richard.component::<HP>().get_mut(|hp| hp.set(500))?;
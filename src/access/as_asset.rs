@@ -1,9 +1,13 @@
 use std::borrow::Borrow;
 
-use super::{AsyncAsset, AsyncComponent, AsyncNonSend, AsyncResource, AsyncWorld};
-use crate::{AccessError, AccessResult, FetchEntity, FetchWorld};
+use super::{
+    AsyncAsset, AsyncComponent, AsyncEntity, AsyncNonSend, AsyncResource, AsyncWorld, EntityPath,
+    VirtualEntity,
+};
+use crate::{AccessError, AccessResult, FetchEntity, FetchVirtualEntity, FetchWorld};
 use bevy::{
     asset::{Asset, AssetId, Handle},
+    ecs::relationship::RelationshipTarget,
     prelude::{Component, Entity, Resource},
 };
 
@@ -14,7 +18,7 @@ pub trait GetHandle {
     fn get_handle(&self) -> AccessResult<Handle<Self::Asset>>;
 }
 
-impl<C: Component + GetHandle> AsyncComponent<C> {
+impl<C: Component + GetHandle, E: VirtualEntity> AsyncComponent<C, E> {
     /// Obtain the underlying [`Asset`] according to [`GetHandle`].
     pub fn asset(&self) -> AccessResult<AsyncAsset<C::Asset>> {
         Ok(AsyncAsset::Strong(self.get(|x| x.get_handle())??))
@@ -46,6 +50,16 @@ impl<T: Component + GetHandle> FetchEntity for AssetOf<T> {
     }
 }
 
+impl<T: Component + GetHandle, R: RelationshipTarget> FetchVirtualEntity<(), EntityPath<R>>
+    for AssetOf<T>
+{
+    type Out = AccessResult<AsyncAsset<T::Asset>>;
+
+    fn fetch_virtual(entity: EntityPath<R>) -> Self::Out {
+        AsyncEntity::from_virtual(entity).component::<T>().asset()
+    }
+}
+
 impl<T: Resource + GetHandle> FetchWorld for AssetOf<T> {
     type Out = AccessResult<AsyncAsset<T::Asset>>;
 
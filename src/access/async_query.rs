@@ -1,15 +1,24 @@
-use crate::access::AsyncEntity;
+use crate::access::{AsyncEntity, AsyncWorld};
 use crate::executor::with_world_mut;
+use crate::sync::oneshot::ChannelOut;
 use crate::{access::get_entity::VirtualEntity, OwnedQueryState};
-use bevy::ecs::query::IterQueryData;
+use bevy::ecs::entity::EntityHashSet;
+use bevy::ecs::query::{IterQueryData, QueryState};
 #[allow(unused)]
 use bevy::ecs::system::Query;
+use bevy::ecs::world::World;
 use bevy::ecs::{
     entity::Entity,
     query::{QueryData, QueryFilter},
 };
+use futures::future::{ready, Either};
+use futures::stream::{unfold, FusedStream};
+use futures::FutureExt;
 use std::any::type_name;
+use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::rc::Rc;
 use std::{borrow::Borrow, marker::PhantomData, ops::Deref};
 
 /// Async version of [`Query`]
@@ -117,6 +126,139 @@ impl<T: IterQueryData + 'static, F: QueryFilter + 'static> AsyncQuery<T, F> {
             }
         })
     }
+
+    /// Run a function on each item and collect the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let ints = AsyncWorld.query::<&Int>().iter_collect(|x| x.0);
+    /// assert_eq!(ints, vec![4]);
+    /// # });
+    /// ```
+    pub fn iter_collect<A>(&self, mut f: impl FnMut(T::Item<'_, '_>) -> A) -> Vec<A> {
+        with_world_mut(move |w| {
+            let mut state = OwnedQueryState::<T, F>::new(w);
+            state.iter_mut().map(&mut f).collect()
+        })
+    }
+
+    /// Wait until some entity matching the query satisfies a predicate,
+    /// and obtain the first one found.
+    ///
+    /// The query is checked before each run of the executor, see [`AsyncWorld::watch`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let four = AsyncWorld.query::<&Int>().wait_any(|x| x.0 == 4).await;
+    /// assert_eq!(four.entity().component::<Int>().get(|x| x.0)?, 4);
+    /// # });
+    /// ```
+    pub fn wait_any(
+        &self,
+        mut f: impl FnMut(T::Item<'_, '_>) -> bool + 'static,
+    ) -> ChannelOut<AsyncEntityQuery<T, F>> {
+        AsyncWorld.watch(move |w| {
+            let mut state = OwnedQueryState::<(Entity, T), F>::new(w);
+            let entity = state
+                .iter_mut()
+                .find_map(|(entity, item)| f(item).then_some(entity));
+            entity.map(|entity| AsyncEntityQuery {
+                entity,
+                p: PhantomData,
+            })
+        })
+    }
+
+    /// Create a `Stream` that yields an [`AsyncEntityQuery`] each time an entity starts
+    /// matching the query, entities matching the query on creation are not yielded.
+    ///
+    /// If an entity stops matching the query, it will be yielded again if it matches again.
+    /// The query is checked before each run of the executor, see [`AsyncWorld::watch`].
+    ///
+    /// # Performance
+    ///
+    /// An entity can start matching without any of its components being added,
+    /// so each stream iterates all matching entities before each run of the executor.
+    /// For queries matching many entities, consider [`AsyncEntity::watch_added`] or an observer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # use futures::StreamExt;
+    /// let mut added = AsyncWorld.query::<&Int>().stream_added();
+    /// let entity = AsyncWorld.spawn_bundle(Int(1));
+    /// assert_eq!(added.next().await.unwrap().id(), entity.id());
+    /// # });
+    /// ```
+    pub fn stream_added(
+        &self,
+    ) -> impl FusedStream<Item = AsyncEntityQuery<T, F>> + Unpin + 'static {
+        let matching = Rc::new(RefCell::new(with_world_mut(Matching::<T, F>::new)));
+        let pending = VecDeque::new();
+        unfold((matching, pending), move |(matching, pending)| {
+            let next = |matching, mut pending: VecDeque<Entity>| {
+                let item = pending.pop_front().map(|entity| AsyncEntityQuery {
+                    entity,
+                    p: PhantomData,
+                });
+                item.map(|item| (item, (matching, pending)))
+            };
+            if !pending.is_empty() {
+                return Either::Left(ready(next(matching, pending)));
+            }
+            let watched = matching.clone();
+            Either::Right(
+                AsyncWorld
+                    .watch(move |w| {
+                        let added = watched.borrow_mut().added(w);
+                        (!added.is_empty()).then_some(added)
+                    })
+                    .map(move |added| next(matching, added)),
+            )
+        })
+    }
+}
+
+/// Entities matching a query in [`AsyncQuery::stream_added`].
+///
+/// Keeps its [`QueryState`] so archetypes are matched incrementally,
+/// and reuses its sets between checks.
+struct Matching<T: IterQueryData + 'static, F: QueryFilter + 'static> {
+    state: QueryState<(Entity, T), F>,
+    known: EntityHashSet,
+    current: EntityHashSet,
+}
+
+impl<T: IterQueryData + 'static, F: QueryFilter + 'static> Matching<T, F> {
+    fn new(world: &mut World) -> Self {
+        let mut state = QueryState::new(world);
+        let known = state.iter(world).map(|(entity, _)| entity).collect();
+        Matching {
+            state,
+            known,
+            current: EntityHashSet::default(),
+        }
+    }
+
+    /// Returns the entities that started matching since the last check.
+    fn added(&mut self, world: &World) -> VecDeque<Entity> {
+        self.current.clear();
+        self.current
+            .extend(self.state.iter(world).map(|(entity, _)| entity));
+        let added = self
+            .current
+            .iter()
+            .filter(|entity| !self.known.contains(*entity))
+            .copied()
+            .collect();
+        std::mem::swap(&mut self.known, &mut self.current);
+        added
+    }
 }
 
 /// Add method to [`AsyncQuery`] through deref.
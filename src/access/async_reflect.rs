@@ -0,0 +1,247 @@
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::entity::Entity;
+use bevy::ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy::ecs::world::World;
+use bevy::reflect::{PartialReflect, Reflect, ReflectFromReflect, TypeRegistration, TypeRegistry};
+use std::any::type_name;
+use std::borrow::Cow;
+
+use crate::access::get_entity::VirtualEntity;
+use crate::access::AsyncEntity;
+use crate::executor::{with_world_mut, with_world_ref};
+use crate::{AccessError, AccessResult};
+
+/// Error of a type path or [`ComponentId`] not registered with [`ReflectComponent`].
+const NOT_REGISTERED: AccessError =
+    AccessError::Custom("component not registered with ReflectComponent");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ReflectTarget {
+    TypePath(Cow<'static, str>),
+    Id(ComponentId),
+}
+
+/// Async access to a component by reflection, using [`ReflectComponent`] from the [`AppTypeRegistry`].
+///
+/// # Note
+///
+/// This does not mean the component, its registration or the entity exists in the world.
+#[derive(Debug, Clone)]
+pub struct AsyncReflectComponent<E: VirtualEntity = Entity> {
+    entity: E,
+    target: ReflectTarget,
+}
+
+impl<E: VirtualEntity> AsyncEntity<E> {
+    /// Get an [`AsyncReflectComponent`] on this entity by the type path of the component,
+    /// i.e. `bevy_transform::components::transform::Transform`.
+    ///
+    /// The component must be registered with `ReflectComponent`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # AsyncWorld.resource::<AppTypeRegistry>().get(|r| r.write().register::<Transform>())?;
+    /// let entity = AsyncWorld.spawn_bundle(Transform::default());
+    /// let patch = Transform::from_xyz(1.0, 2.0, 3.0);
+    /// entity
+    ///     .reflect("bevy_transform::components::transform::Transform")
+    ///     .apply(&patch)?;
+    /// assert_eq!(entity.component::<Transform>().get(|x| x.translation.x)?, 1.0);
+    /// # });
+    /// ```
+    pub fn reflect(&self, type_path: impl Into<Cow<'static, str>>) -> AsyncReflectComponent<E>
+    where
+        E: Clone,
+    {
+        AsyncReflectComponent {
+            entity: self.0.clone(),
+            target: ReflectTarget::TypePath(type_path.into()),
+        }
+    }
+
+    /// Get an [`AsyncReflectComponent`] on this entity by [`ComponentId`].
+    ///
+    /// The component must be registered with `ReflectComponent`.
+    pub fn reflect_by_id(&self, id: ComponentId) -> AsyncReflectComponent<E>
+    where
+        E: Clone,
+    {
+        AsyncReflectComponent {
+            entity: self.0.clone(),
+            target: ReflectTarget::Id(id),
+        }
+    }
+}
+
+impl<E: VirtualEntity> AsyncReflectComponent<E> {
+    /// Obtain the entity of this component.
+    pub fn entity(self) -> AsyncEntity<E> {
+        AsyncEntity(self.entity)
+    }
+
+    /// Find the registration of the component type.
+    ///
+    /// # Errors
+    ///
+    /// [`AccessError::Custom`] if not registered with [`ReflectComponent`].
+    fn registration<'r>(
+        &self,
+        world: &World,
+        registry: &'r TypeRegistry,
+    ) -> AccessResult<(&'r TypeRegistration, &'r ReflectComponent)> {
+        let registration = match &self.target {
+            ReflectTarget::TypePath(path) => registry.get_with_type_path(path),
+            ReflectTarget::Id(id) => world
+                .components()
+                .get_info(*id)
+                .and_then(|info| info.type_id())
+                .and_then(|type_id| registry.get(type_id)),
+        };
+        registration
+            .and_then(|registration| Some((registration, registration.data::<ReflectComponent>()?)))
+            .ok_or(NOT_REGISTERED)
+    }
+
+    /// Run a function on the reflected component, and obtain the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # AsyncWorld.resource::<AppTypeRegistry>().get(|r| r.write().register::<Transform>())?;
+    /// # let entity = AsyncWorld.spawn_bundle(Transform::default());
+    /// let has_translation = entity
+    ///     .reflect("bevy_transform::components::transform::Transform")
+    ///     .get(|x| x.reflect_ref().as_struct().unwrap().field("translation").is_some())?;
+    /// # });
+    /// ```
+    pub fn get<T>(&self, f: impl FnOnce(&dyn Reflect) -> T) -> AccessResult<T> {
+        with_world_ref(|world| {
+            let registry = app_type_registry(world)?;
+            let registry = registry.read();
+            let entity = self.entity.try_get_entity(world)?;
+            let (registration, reflect) = self.registration(world, &registry)?;
+            let entity_ref = world
+                .get_entity(entity)
+                .map_err(|_| AccessError::EntityNotFound(entity))?;
+            reflect
+                .reflect(entity_ref)
+                .map(f)
+                .ok_or(AccessError::ComponentNotFound {
+                    entity,
+                    name: registration.type_info().type_path(),
+                })
+        })
+    }
+
+    /// Run a function on the mutable reflected component, and obtain the result.
+    ///
+    /// # Errors
+    ///
+    /// [`AccessError::ComponentNotFound`] if the component is missing or immutable.
+    pub fn get_mut<T>(&self, f: impl FnOnce(&mut dyn Reflect) -> T) -> AccessResult<T> {
+        with_world_mut(|world| {
+            let registry = app_type_registry(world)?;
+            let registry = registry.read();
+            let entity = self.entity.try_get_entity(world)?;
+            let (registration, reflect) = self.registration(world, &registry)?;
+            let name = registration.type_info().type_path();
+            let is_mutable = world
+                .components()
+                .get_id(registration.type_id())
+                .and_then(|id| world.components().get_info(id))
+                .is_none_or(|info| info.mutable());
+            if !is_mutable {
+                return Err(AccessError::ComponentNotFound { entity, name });
+            }
+            let mut entity_mut = world
+                .get_entity_mut(entity)
+                .map_err(|_| AccessError::EntityNotFound(entity))?;
+            reflect
+                .reflect_mut(&mut entity_mut)
+                .map(|mut x| f(&mut *x))
+                .ok_or(AccessError::ComponentNotFound { entity, name })
+        })
+    }
+
+    /// Patch the component by applying a [`PartialReflect`] value, e.g. a `DynamicStruct`.
+    ///
+    /// # Errors
+    ///
+    /// * If the component is missing, unregistered or immutable.
+    /// * [`AccessError::DowncastFailed`] if the value cannot be applied to the component.
+    pub fn apply(&self, value: &dyn PartialReflect) -> AccessResult {
+        self.get_mut(|component| {
+            component
+                .try_apply(value)
+                .map_err(|_| AccessError::DowncastFailed {
+                    name: component
+                        .get_represented_type_info()
+                        .map_or(type_name::<dyn Reflect>(), |info| info.type_path()),
+                })
+        })?
+    }
+
+    /// Insert the component from a [`PartialReflect`] value, replacing the existing one.
+    ///
+    /// # Errors
+    ///
+    /// [`AccessError::DowncastFailed`] if the value does not represent the component type,
+    /// or cannot be converted to it, e.g. a `DynamicStruct` with missing fields.
+    pub fn insert(&self, value: &dyn PartialReflect) -> AccessResult {
+        with_world_mut(|world| {
+            let registry = app_type_registry(world)?;
+            let registry = registry.read();
+            let entity = self.entity.try_get_entity(world)?;
+            let (registration, reflect) = self.registration(world, &registry)?;
+            let represented = value.get_represented_type_info().map(|info| info.type_id());
+            let downcast_failed = AccessError::DowncastFailed {
+                name: registration.type_info().type_path(),
+            };
+            if represented != Some(registration.type_id()) {
+                return Err(downcast_failed);
+            }
+            // `ReflectComponent::insert` panics if the value cannot be converted.
+            let value = registration
+                .data::<ReflectFromReflect>()
+                .and_then(|from_reflect| from_reflect.from_reflect(value))
+                .ok_or(downcast_failed)?;
+            let mut entity_mut = world
+                .get_entity_mut(entity)
+                .map_err(|_| AccessError::EntityNotFound(entity))?;
+            reflect.insert(&mut entity_mut, value.as_partial_reflect(), &registry);
+            Ok(())
+        })
+    }
+
+    /// Remove the component, does nothing if not present.
+    pub fn remove(&self) -> AccessResult {
+        with_world_mut(|world| {
+            let registry = app_type_registry(world)?;
+            let registry = registry.read();
+            let entity = self.entity.try_get_entity(world)?;
+            let (_, reflect) = self.registration(world, &registry)?;
+            let mut entity_mut = world
+                .get_entity_mut(entity)
+                .map_err(|_| AccessError::EntityNotFound(entity))?;
+            reflect.remove(&mut entity_mut);
+            Ok(())
+        })
+    }
+
+    /// Returns `true` if the entity exists and has the component.
+    pub fn exists(&self) -> bool {
+        self.get(|_| ()).is_ok()
+    }
+}
+
+fn app_type_registry(world: &World) -> AccessResult<AppTypeRegistry> {
+    world
+        .get_resource::<AppTypeRegistry>()
+        .cloned()
+        .ok_or(AccessError::ResourceNotFound {
+            name: type_name::<AppTypeRegistry>(),
+        })
+}
@@ -0,0 +1,239 @@
+use bevy::ecs::{
+    entity::Entity,
+    hierarchy::Children,
+    name::Name,
+    query::Without,
+    relationship::{Relationship, RelationshipTarget},
+    world::World,
+};
+use std::{
+    any::type_name,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::access::{get_entity::VirtualEntity, AsyncEntity, AsyncWorld};
+use crate::{AccessError, AccessResult, OwnedReadonlyQueryState};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PathSegment {
+    Name(String),
+    Index(usize),
+    Parent,
+}
+
+/// An owned [`VirtualEntity`] resolved by a path of [`Name`]s, i.e. `"Player/Armature/Hand.R"`.
+///
+/// # Syntax
+///
+/// Segments are separated by `/`, each segment can be
+///
+/// * `..`: the parent.
+/// * `.`: the current entity, or an empty segment.
+/// * `#n`: the `n`th child.
+/// * Anything else: the first child with the [`Name`].
+///
+/// # Relationship
+///
+/// By default [`Children`] and its [`ChildOf`](bevy::ecs::hierarchy::ChildOf) are used,
+/// use [`EntityPath::with_relationship`] for other relationships.
+#[derive(Debug)]
+pub struct EntityPath<R: RelationshipTarget = Children> {
+    root: Option<Entity>,
+    segments: Vec<PathSegment>,
+    p: PhantomData<R>,
+}
+
+impl<R: RelationshipTarget> Clone for EntityPath<R> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root,
+            segments: self.segments.clone(),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<R: RelationshipTarget> PartialEq for EntityPath<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root && self.segments == other.segments
+    }
+}
+
+impl<R: RelationshipTarget> Eq for EntityPath<R> {}
+
+impl<R: RelationshipTarget> Hash for EntityPath<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.root.hash(state);
+        self.segments.hash(state);
+    }
+}
+
+fn parse(path: &str) -> Vec<PathSegment> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .map(|segment| {
+            if segment == ".." {
+                return PathSegment::Parent;
+            }
+            match segment.strip_prefix('#').map(str::parse) {
+                Some(Ok(index)) => PathSegment::Index(index),
+                _ => PathSegment::Name(segment.to_owned()),
+            }
+        })
+        .collect()
+}
+
+impl EntityPath {
+    /// Create a path starting from entities without a parent,
+    /// the first segment must be a [`Name`].
+    pub fn new(path: &str) -> Self {
+        Self {
+            root: None,
+            segments: parse(path),
+            p: PhantomData,
+        }
+    }
+
+    /// Create a path relative to an entity.
+    pub fn relative(entity: Entity, path: &str) -> Self {
+        Self {
+            root: Some(entity),
+            segments: parse(path),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<R: RelationshipTarget> EntityPath<R> {
+    /// Resolve the path with a different relationship.
+    pub fn with_relationship<R2: RelationshipTarget>(self) -> EntityPath<R2> {
+        EntityPath {
+            root: self.root,
+            segments: self.segments,
+            p: PhantomData,
+        }
+    }
+
+    /// Obtain the entity the path is relative to, if any.
+    pub fn root(&self) -> Option<Entity> {
+        self.root
+    }
+}
+
+impl<R: RelationshipTarget> Display for EntityPath<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i > 0 {
+                f.write_str("/")?;
+            }
+            match segment {
+                PathSegment::Name(name) => f.write_str(name)?,
+                PathSegment::Index(index) => write!(f, "#{index}")?,
+                PathSegment::Parent => f.write_str("..")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parent_not_found<R: RelationshipTarget>() -> AccessError {
+    AccessError::TypedParentNotFound {
+        query: type_name::<R::Relationship>(),
+    }
+}
+
+impl<R: RelationshipTarget> VirtualEntity for EntityPath<R> {
+    fn try_get_entity(&self, world: &World) -> AccessResult<Entity> {
+        let mut segments = self.segments.iter();
+        let mut entity = match (self.root, segments.next()) {
+            (Some(root), None) => return Ok(root),
+            (Some(root), Some(segment)) => resolve::<R>(world, root, segment)?,
+            (None, Some(PathSegment::Name(name))) => {
+                let mut query =
+                    OwnedReadonlyQueryState::<(Entity, &Name), Without<R::Relationship>>::new(
+                        world,
+                    );
+                query
+                    .try_iter()
+                    .and_then(|mut iter| {
+                        iter.find_map(|(entity, x)| (x.as_str() == name).then_some(entity))
+                    })
+                    .ok_or(AccessError::NamedChildNotFound)?
+            }
+            (None, Some(PathSegment::Index(index))) => {
+                return Err(AccessError::ChildNotFound { index: *index })
+            }
+            (None, Some(PathSegment::Parent)) => return Err(parent_not_found::<R>()),
+            (None, None) => return Err(AccessError::NamedChildNotFound),
+        };
+        for segment in segments {
+            entity = resolve::<R>(world, entity, segment)?;
+        }
+        Ok(entity)
+    }
+}
+
+fn resolve<R: RelationshipTarget>(
+    world: &World,
+    entity: Entity,
+    segment: &PathSegment,
+) -> AccessResult<Entity> {
+    match segment {
+        PathSegment::Name(name) => world
+            .get::<R>(entity)
+            .and_then(|children| {
+                children.iter().find(|child| {
+                    world
+                        .get::<Name>(*child)
+                        .is_some_and(|x| x.as_str() == name)
+                })
+            })
+            .ok_or(AccessError::NamedChildNotFound),
+        PathSegment::Index(index) => world
+            .get::<R>(entity)
+            .and_then(|children| children.iter().nth(*index))
+            .ok_or(AccessError::ChildNotFound { index: *index }),
+        PathSegment::Parent => world
+            .get::<R::Relationship>(entity)
+            .map(|parent| parent.get())
+            .ok_or_else(parent_not_found::<R>),
+    }
+}
+
+impl AsyncWorld {
+    /// Obtain an entity by an [`EntityPath`] starting from entities without a parent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let player = AsyncWorld.spawn_bundle(Name::new("Player"));
+    /// let hand = player.spawn_child(Name::new("Hand.R"))?;
+    /// assert_eq!(AsyncWorld.entity_path("Player/Hand.R").try_get_id()?, hand.id());
+    /// # });
+    /// ```
+    pub fn entity_path(&self, path: &str) -> AsyncEntity<EntityPath> {
+        AsyncEntity(EntityPath::new(path))
+    }
+}
+
+impl AsyncEntity {
+    /// Obtain an entity by an [`EntityPath`] relative to this entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let player = AsyncWorld.spawn_bundle(Name::new("Player"));
+    /// let left = player.spawn_child(Name::new("Hand.L"))?;
+    /// let right = player.spawn_child(Name::new("Hand.R"))?;
+    /// assert_eq!(left.path("../Hand.R").try_get_id()?, right.id());
+    /// assert_eq!(player.path("#0").try_get_id()?, left.id());
+    /// # });
+    /// ```
+    pub fn path(&self, path: &str) -> AsyncEntity<EntityPath> {
+        AsyncEntity(EntityPath::relative(self.0, path))
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{access::AsyncEntity, AccessError, AccessResult, OwnedReadonlyQueryState};
+use crate::{
+    access::{AsyncEntity, AsyncWorld},
+    AccessError, AccessResult, OwnedReadonlyQueryState,
+};
 use bevy::ecs::{
     entity::Entity,
     hierarchy::{ChildOf, Children},
@@ -440,3 +443,123 @@ impl<E: VirtualEntity> AsyncEntity<E> {
         AsyncEntity(NamedDescendant::new(self.0, name))
     }
 }
+
+/// Find the only entity with a [`Name`] in the `World`.
+///
+/// # Errors
+///
+/// [`AccessError::NoEntityFound`] or [`AccessError::TooManyEntities`]
+/// if not exactly one entity has the name, the query in the error is [`Name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByName<S: AsRef<str> = &'static str>(pub S);
+
+impl<S: AsRef<str>> VirtualEntity for ByName<S> {
+    fn try_get_entity(&self, world: &World) -> AccessResult<Entity> {
+        let name = self.0.as_ref();
+        let mut query = OwnedReadonlyQueryState::<(Entity, &Name), ()>::new(world);
+        let found = query
+            .try_iter()
+            .into_iter()
+            .flatten()
+            .filter_map(|(entity, x)| (x.as_str() == name).then_some(entity));
+        single(found, type_name::<Name>)
+    }
+}
+
+/// Find the only entity in the `World` that satisfies a filter.
+///
+/// # Errors
+///
+/// [`AccessError::NoEntityFound`] or [`AccessError::TooManyEntities`]
+/// if not exactly one entity satisfies the filter.
+#[derive(Debug)]
+pub struct Singleton<F: QueryFilter + 'static>(PhantomData<F>);
+
+impl<F: QueryFilter + 'static> Default for Singleton<F> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<F: QueryFilter + 'static> Clone for Singleton<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: QueryFilter + 'static> Copy for Singleton<F> {}
+
+impl<F: QueryFilter + 'static> VirtualEntity for Singleton<F> {
+    fn try_get_entity(&self, world: &World) -> AccessResult<Entity> {
+        let mut query = OwnedReadonlyQueryState::<Entity, F>::new(world);
+        single(query.try_iter().into_iter().flatten(), type_name::<F>)
+    }
+}
+
+/// Find the first entity in the `World` that satisfies a filter.
+///
+/// # Errors
+///
+/// [`AccessError::NoEntityFound`] if no entity satisfies the filter.
+#[derive(Debug)]
+pub struct ByQuery<F: QueryFilter + 'static>(PhantomData<F>);
+
+impl<F: QueryFilter + 'static> Default for ByQuery<F> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<F: QueryFilter + 'static> Clone for ByQuery<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: QueryFilter + 'static> Copy for ByQuery<F> {}
+
+impl<F: QueryFilter + 'static> VirtualEntity for ByQuery<F> {
+    fn try_get_entity(&self, world: &World) -> AccessResult<Entity> {
+        let mut query = OwnedReadonlyQueryState::<Entity, F>::new(world);
+        query
+            .try_iter()
+            .and_then(|mut iter| iter.next())
+            .ok_or(AccessError::NoEntityFound {
+                query: type_name::<F>(),
+            })
+    }
+}
+
+/// Returns the only entity, `query` names the lookup in errors.
+fn single(
+    mut iter: impl Iterator<Item = Entity>,
+    query: impl FnOnce() -> &'static str,
+) -> AccessResult<Entity> {
+    match (iter.next(), iter.next()) {
+        (Some(entity), None) => Ok(entity),
+        (None, _) => Err(AccessError::NoEntityFound { query: query() }),
+        (Some(_), Some(_)) => Err(AccessError::TooManyEntities { query: query() }),
+    }
+}
+
+impl AsyncWorld {
+    /// Obtain an entity by a global lookup, i.e. [`Singleton`] or [`ByQuery`],
+    /// resolved each time the entity is accessed.
+    ///
+    /// Use [`AsyncEntity::from_virtual`] for lookups with values, i.e. [`ByName`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let str = AsyncWorld.entity_by::<Singleton<With<Str>>>();
+    /// assert_eq!(str.component::<Str>().get(|x| x.0)?, "Ferris");
+    /// let boss = AsyncWorld.spawn_bundle(Name::new("Boss"));
+    /// let by_name = AsyncEntity::from_virtual(ByName("Boss"));
+    /// assert_eq!(by_name.try_get_id()?, boss.id());
+    /// # });
+    /// ```
+    pub fn entity_by<E: VirtualEntity + Default>(&self) -> AsyncEntity<E> {
+        AsyncEntity(E::default())
+    }
+}
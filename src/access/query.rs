@@ -228,6 +228,11 @@ impl<D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> OwnedReadonlyQuer
     pub fn iter(&mut self) -> QueryIter<'_, '_, D::ReadOnly, F> {
         self.state.as_mut().unwrap().iter(self.world)
     }
+
+    /// Returns `None` instead of panicking if the query's components are not registered.
+    pub(crate) fn try_iter(&mut self) -> Option<QueryIter<'_, '_, D::ReadOnly, F>> {
+        Some(self.state.as_mut()?.iter(self.world))
+    }
 }
 
 impl<'s, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> IntoIterator
@@ -245,8 +250,8 @@ impl<D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> Drop
     for OwnedReadonlyQueryState<'_, D, F>
 {
     fn drop(&mut self) {
-        self.world
-            .non_send::<QueryCache>()
-            .push_query_state(self.state.take().unwrap());
+        if let Some(state) = self.state.take() {
+            self.world.non_send::<QueryCache>().push_query_state(state);
+        }
     }
 }
@@ -0,0 +1,99 @@
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::{FilteredAccess, QueryData, ReleaseStateQueryData, SingleEntityQueryData};
+use std::any::type_name;
+
+use crate::access::get_entity::VirtualEntity;
+use crate::access::AsyncEntity;
+use crate::executor::{with_world_mut, with_world_ref};
+use crate::{AccessError, AccessResult};
+
+impl<E: VirtualEntity> AsyncEntity<E> {
+    /// Resolve the entity once and run a function on multiple of its components,
+    /// borrowed by a [`QueryData`], e.g. `(&mut Transform, &mut Health)`.
+    ///
+    /// All components are validated before the function is called,
+    /// if any of them is missing, the function is not called and nothing is written.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessError::EntityNotFound`] if the entity does not exist.
+    /// * [`AccessError::QueryConditionNotMet`] if the query does not match,
+    ///   see [`AsyncEntity::missing_components`] for the missing components.
+    ///
+    /// # Panics
+    ///
+    /// If the query has conflicting accesses, e.g. `(&mut Health, &Health)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let entity = AsyncWorld.spawn_bundle((Int(1), Transform::default()));
+    /// entity.transaction::<(&mut Int, &mut Transform), _>(|(mut int, mut transform)| {
+    ///     int.0 += 1;
+    ///     transform.translation.x = int.0 as f32;
+    /// })?;
+    /// // `Str` is missing, so `Int` is not modified.
+    /// let result = entity.transaction::<(&mut Int, &Str), _>(|(mut int, _)| int.0 = 0);
+    /// assert!(result.is_err());
+    /// assert_eq!(entity.component::<Int>().get(|x| x.0)?, 2);
+    /// # });
+    /// ```
+    pub fn transaction<Q: ReleaseStateQueryData + SingleEntityQueryData, T>(
+        &self,
+        f: impl FnOnce(Q::Item<'_, 'static>) -> T,
+    ) -> AccessResult<T> {
+        with_world_mut(|world| {
+            let entity = self.0.try_get_entity(world)?;
+            let mut entity_mut = world
+                .get_entity_mut(entity)
+                .map_err(|_| AccessError::EntityNotFound(entity))?;
+            // Does not register the components of `Q`.
+            entity_mut.get_components_mut::<Q>().map(f).map_err(|_| {
+                AccessError::QueryConditionNotMet {
+                    entity,
+                    query: type_name::<Q>(),
+                }
+            })
+        })
+    }
+
+    /// Returns the [`ComponentId`]s of the components required by a [`QueryData`]
+    /// that are missing on the entity, e.g. why [`AsyncEntity::transaction`] failed.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessError::EntityNotFound`] if the entity does not exist.
+    /// * [`AccessError::QueryConditionNotMet`] if a component has never been registered,
+    ///   and therefore has no [`ComponentId`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let entity = AsyncWorld.spawn_bundle(Int(1));
+    /// let missing = entity.missing_components::<(&mut Int, &Str)>()?;
+    /// assert_eq!(missing.len(), 1);
+    /// # });
+    /// ```
+    pub fn missing_components<Q: QueryData>(&self) -> AccessResult<Vec<ComponentId>> {
+        with_world_ref(|world| {
+            let entity = self.0.try_get_entity(world)?;
+            let entity_ref = world
+                .get_entity(entity)
+                .map_err(|_| AccessError::EntityNotFound(entity))?;
+            let state =
+                Q::get_state(world.components()).ok_or(AccessError::QueryConditionNotMet {
+                    entity,
+                    query: type_name::<Q>(),
+                })?;
+            let mut access = FilteredAccess::default();
+            Q::update_component_access(&state, &mut access);
+            Ok(access
+                .required()
+                .iter()
+                .filter(|component| !entity_ref.contains_id(*component))
+                .collect())
+        })
+    }
+}
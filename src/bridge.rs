@@ -0,0 +1,164 @@
+//! Message passing between worlds running `bevy_defer`.
+use bevy::app::{AppLabel, InternedAppLabel};
+use bevy::ecs::resource::Resource;
+use bevy::ecs::schedule::Schedules;
+use bevy::ecs::world::World;
+use futures::channel::oneshot;
+use futures::future::Either;
+use rustc_hash::FxHashMap;
+use std::future::{ready, Future};
+use std::sync::{Arc, Mutex, Weak};
+
+use crate::executor::with_world_ref;
+use crate::{AccessError, AccessResult, AsyncWorld, BeforeAsyncExecutor};
+
+type WorldCommand = Box<dyn FnOnce(&mut World) + Send>;
+type Inbox = Arc<Mutex<Vec<WorldCommand>>>;
+
+/// A registry of worlds reachable by [`AsyncWorld::in_world`], shared by all connected worlds.
+///
+/// Each world, e.g. the main world, a sub-app or a standalone simulation `App`,
+/// needs its own [`AsyncPlugin`](crate::AsyncPlugin) and is identified by an [`AppLabel`].
+///
+/// # Sub-apps
+///
+/// Since the executor is `!Send`, a sub-app must be updated on the thread it was built on,
+/// and requires the `Time` and `AppTypeRegistry` resources if not added by other plugins.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::app::AppLabel;
+/// # use bevy_defer::{AsyncPlugin, AsyncWorlds};
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, AppLabel)]
+/// struct MainWorld;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash, AppLabel)]
+/// struct Simulation;
+///
+/// let mut app = App::new();
+/// app.add_plugins(AsyncPlugin::default_settings());
+/// let mut simulation = App::new();
+/// simulation.add_plugins(AsyncPlugin::default_settings());
+///
+/// let worlds = AsyncWorlds::default();
+/// worlds.connect(MainWorld, app.world_mut());
+/// worlds.connect(Simulation, simulation.world_mut());
+/// ```
+#[derive(Clone, Default, Resource)]
+pub struct AsyncWorlds(Arc<Mutex<FxHashMap<InternedAppLabel, Weak<Mutex<Vec<WorldCommand>>>>>>);
+
+impl std::fmt::Debug for AsyncWorlds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.0.lock().unwrap().keys())
+            .finish()
+    }
+}
+
+/// Commands sent to this world by [`AsyncWorld::in_world`].
+#[derive(Resource)]
+struct WorldInbox(Inbox);
+
+impl AsyncWorlds {
+    /// Make a world reachable by `label` and allow it to reach other connected worlds.
+    ///
+    /// Commands are run before each run of the world's executor.
+    /// Connecting another world with the same label replaces the previous one.
+    pub fn connect(&self, label: impl AppLabel, world: &mut World) {
+        let inbox = match world.get_resource::<WorldInbox>() {
+            Some(inbox) => inbox.0.clone(),
+            None => {
+                let inbox = Inbox::default();
+                world.insert_resource(WorldInbox(inbox.clone()));
+                world
+                    .resource_mut::<Schedules>()
+                    .add_systems(BeforeAsyncExecutor, run_world_inbox);
+                inbox
+            }
+        };
+        world.insert_resource(self.clone());
+        self.0
+            .lock()
+            .unwrap()
+            .insert(label.intern(), Arc::downgrade(&inbox));
+    }
+
+    /// Returns `true` if a world with `label` is connected and not dropped.
+    pub fn contains(&self, label: impl AppLabel) -> bool {
+        self.0
+            .lock()
+            .unwrap()
+            .get(&label.intern())
+            .is_some_and(|inbox| inbox.strong_count() > 0)
+    }
+
+    fn send(&self, label: InternedAppLabel, command: WorldCommand) -> AccessResult {
+        let inbox = self
+            .0
+            .lock()
+            .unwrap()
+            .get(&label)
+            .and_then(Weak::upgrade)
+            .ok_or(AccessError::WorldNotFound)?;
+        inbox.lock().unwrap().push(command);
+        Ok(())
+    }
+}
+
+fn run_world_inbox(world: &mut World) {
+    let commands = std::mem::take(&mut *world.resource::<WorldInbox>().0.lock().unwrap());
+    for command in commands {
+        command(world);
+    }
+}
+
+impl AsyncWorld {
+    /// Run a function on another world connected by [`AsyncWorlds`] and obtain its result.
+    ///
+    /// The function runs before the next run of the other world's executor,
+    /// use `spawn_task` on the other world to run a `bevy_defer` future there.
+    ///
+    /// # Errors
+    ///
+    /// [`AccessError::WorldNotFound`] if the world is not connected,
+    /// or dropped before the function is run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # #[derive(Debug, Clone, PartialEq, Eq, Hash, bevy::app::AppLabel)]
+    /// # struct Simulation;
+    /// let result = AsyncWorld.in_world(Simulation, |world| {
+    ///     world.spawn_task(async {
+    ///         AsyncWorld.sleep(1.0).await;
+    ///         Ok(())
+    ///     });
+    /// }).await;
+    /// assert_eq!(result, Err(AccessError::WorldNotFound));
+    /// # });
+    /// ```
+    pub fn in_world<T: Send + 'static>(
+        &self,
+        label: impl AppLabel,
+        f: impl FnOnce(&mut World) -> T + Send + 'static,
+    ) -> impl Future<Output = AccessResult<T>> + 'static {
+        let Some(worlds) = with_world_ref(|world| world.get_resource::<AsyncWorlds>().cloned())
+        else {
+            return Either::Left(ready(Err(AccessError::WorldNotFound)));
+        };
+        let (sender, receiver) = oneshot::channel();
+        let sent = worlds.send(
+            label.intern(),
+            Box::new(move |world| {
+                let _ = sender.send(f(world));
+            }),
+        );
+        Either::Right(async move {
+            sent?;
+            receiver.await.map_err(|_| AccessError::WorldNotFound)
+        })
+    }
+}
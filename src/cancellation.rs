@@ -63,6 +63,11 @@ impl SyncCancellation {
         self.0.store(true, Ordering::Relaxed)
     }
 
+    /// Returns `true` if cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
     /// Cancel a running task when the handle is dropped.
     pub fn cancel_on_drop(self) -> CancelOnDropSync {
         CancelOnDropSync(self)
@@ -15,6 +15,7 @@ use bevy::ecs::{bundle::Bundle, resource::Resource, schedule::ScheduleLabel, wor
 use bevy::prelude::SystemInput;
 use bevy::state::state::{FreelyMutableState, NextState, State, States};
 use bevy::tasks::AsyncComputeTaskPool;
+use bevy::time::{Fixed, Real};
 use futures::future::ready;
 use futures::future::Either;
 use futures::stream::FusedStream;
@@ -446,6 +447,113 @@ impl AsyncWorld {
         Either::Left(receiver.into_out())
     }
 
+    /// Pause the future until [`AsyncWorld::now`] reaches `elapsed`,
+    /// completes immediately if already reached.
+    ///
+    /// Unlike chaining [`AsyncWorld::sleep`], this does not accumulate drift.
+    /// This is not affected by [`AsyncWorld::pause_tag`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let start = AsyncWorld.now();
+    /// for beat in 1..4 {
+    ///     AsyncWorld.sleep_until(start + std::time::Duration::from_millis(100) * beat).await;
+    /// }
+    /// # });
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub fn sleep_until(&self, elapsed: Duration) -> MaybeChannelOut<()> {
+        if elapsed <= self.now() {
+            return Either::Right(ready(()));
+        }
+        let (sender, receiver) = channel();
+        QUERY_QUEUE.with(|queue| queue.timed_at(elapsed, sender));
+        Either::Left(receiver.into_out())
+    }
+
+    /// Pause the future until [`AsyncWorld::frame_count`] reaches `frame`,
+    /// completes immediately if already reached.
+    ///
+    /// This is not affected by [`AsyncWorld::pause_tag`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let frame = AsyncWorld.frame_count();
+    /// AsyncWorld.sleep_until_frame(frame + 4).await
+    /// # });
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub fn sleep_until_frame(&self, frame: u32) -> MaybeChannelOut<()> {
+        if frame <= self.frame_count() {
+            return Either::Right(ready(()));
+        }
+        let (sender, receiver) = channel();
+        QUERY_QUEUE.with(|queue| queue.timed_at_frame(frame, sender));
+        Either::Left(receiver.into_out())
+    }
+
+    /// Pause the future for the duration, according to the `Time<Real>` resource.
+    ///
+    /// Unlike [`AsyncWorld::sleep`], this is not affected by pausing or scaling virtual time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// AsyncWorld.sleep_real(0.1).await
+    /// # });
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub fn sleep_real(&self, duration: impl AsSeconds) -> MaybeChannelOut<()> {
+        self.sleep_on::<Real>(duration)
+    }
+
+    /// Pause the future for the duration, according to the `Time<Fixed>` resource.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// AsyncWorld.sleep_fixed(0.1).await
+    /// # });
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub fn sleep_fixed(&self, duration: impl AsSeconds) -> MaybeChannelOut<()> {
+        self.sleep_on::<Fixed>(duration)
+    }
+
+    /// Pause the future for the duration, according to the `Time<T>` resource.
+    ///
+    /// `Time<T>` is checked once per frame in [`First`](bevy::app::First),
+    /// the future never completes if the resource does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// AsyncWorld.sleep_on::<Real>(0.1).await
+    /// # });
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub fn sleep_on<T: Default + Send + Sync + 'static>(
+        &self,
+        duration: impl AsSeconds,
+    ) -> MaybeChannelOut<()> {
+        let duration = duration.as_duration();
+        if duration <= Duration::ZERO {
+            return Either::Right(ready(()));
+        }
+        let (sender, receiver) = channel();
+        with_world_ref(|world| {
+            QUERY_QUEUE.with(|queue| queue.timed_on::<T>(world, duration, sender))
+        });
+        Either::Left(receiver.into_out())
+    }
+
     /// Pause the future for some frames, according to the `FrameCount` resource.
     ///
     /// # Example
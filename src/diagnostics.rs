@@ -0,0 +1,63 @@
+//! Diagnostics of `bevy_defer`'s runtime.
+use bevy::app::{App, Last, Plugin};
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::ecs::system::{Local, NonSend};
+
+use crate::{AsyncExecutor, QueryQueue};
+
+/// Adds diagnostics of the [`AsyncExecutor`] and [`QueryQueue`] to the
+/// [`DiagnosticsStore`](bevy::diagnostic::DiagnosticsStore).
+///
+/// Measurements are taken once per frame in [`Last`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsyncDiagnosticsPlugin;
+
+impl AsyncDiagnosticsPlugin {
+    /// Number of live tasks on the [`AsyncExecutor`].
+    pub const TASKS: DiagnosticPath = DiagnosticPath::const_new("bevy_defer/tasks");
+    /// Number of times tasks are polled in this frame.
+    pub const POLLS: DiagnosticPath = DiagnosticPath::const_new("bevy_defer/polls");
+    /// Time spent in `run_async_executor` in this frame, in ms.
+    pub const EXECUTOR_TIME: DiagnosticPath = DiagnosticPath::const_new("bevy_defer/executor_time");
+    /// Number of pending queries in `run_watch_queries`.
+    pub const WATCH_QUERIES: DiagnosticPath = DiagnosticPath::const_new("bevy_defer/watch_queries");
+    /// Number of pending tasks in `run_fixed_queue`.
+    pub const FIXED_QUEUE: DiagnosticPath = DiagnosticPath::const_new("bevy_defer/fixed_queue");
+    /// Number of pending `sleep` and `sleep_frames` timers.
+    pub const TIMERS: DiagnosticPath = DiagnosticPath::const_new("bevy_defer/timers");
+
+    /// Records measurements of the [`AsyncExecutor`] and [`QueryQueue`].
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        executor: NonSend<AsyncExecutor>,
+        queue: NonSend<QueryQueue>,
+        mut last_polls: Local<u64>,
+    ) {
+        let total_polls = executor.0.polls.get();
+        let polls = total_polls - *last_polls;
+        *last_polls = total_polls;
+        let busy = executor.0.busy.take();
+        diagnostics.add_measurement(&Self::TASKS, || executor.0.registry.len() as f64);
+        diagnostics.add_measurement(&Self::POLLS, || polls as f64);
+        diagnostics.add_measurement(&Self::EXECUTOR_TIME, || busy.as_secs_f64() * 1000.0);
+        diagnostics.add_measurement(&Self::WATCH_QUERIES, || {
+            queue.repeat_queue.borrow().len() as f64
+        });
+        diagnostics.add_measurement(&Self::FIXED_QUEUE, || {
+            queue.fixed_queue.borrow().len() as f64
+        });
+        diagnostics.add_measurement(&Self::TIMERS, || queue.pending_timers() as f64);
+    }
+}
+
+impl Plugin for AsyncDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::TASKS))
+            .register_diagnostic(Diagnostic::new(Self::POLLS))
+            .register_diagnostic(Diagnostic::new(Self::EXECUTOR_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::WATCH_QUERIES))
+            .register_diagnostic(Diagnostic::new(Self::FIXED_QUEUE))
+            .register_diagnostic(Diagnostic::new(Self::TIMERS))
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
@@ -124,6 +124,12 @@ pub enum AccessError {
     SystemIdNotFound,
     #[error("not in a state of type {}", fmt(ty))]
     NotInState { ty: &'static str },
+    /// A world is not connected by `AsyncWorlds` or has been dropped.
+    #[error("world not found")]
+    WorldNotFound,
+    /// A future did not complete before its timeout or deadline.
+    #[error("timed out")]
+    Timeout,
     /// A custom message.
     #[error("custom error: {0}")]
     Custom(&'static str),
@@ -1,12 +1,24 @@
+use crate::panics::{TaskPanicHook, TaskPanicked};
 use crate::queue::QueryQueue;
 use crate::reactors::Reactors;
+use crate::registry::{RegisteredTask, TaskOptions, TaskPriority, TaskRegistry};
+use crate::task_local::TaskLocals;
 use async_executor::{LocalExecutor, Task};
 use bevy::asset::AssetServer;
+use bevy::ecs::resource::Resource;
 use bevy::ecs::world::World;
 use bevy::log::error;
+use bevy::platform::time::Instant;
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
-use std::future::Future;
+use std::future::{poll_fn, Future};
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe, Location};
+use std::pin::pin;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::Duration;
 
 scoped_tls_hkt::scoped_thread_local!(pub(crate) static mut WORLD: World);
 scoped_tls_hkt::scoped_thread_local!(pub(crate) static WORLD_READ_ONLY: World);
@@ -35,8 +47,11 @@ pub(crate) fn with_world_mut<T>(f: impl FnOnce(&mut World) -> T) -> T {
 
 scoped_tls_hkt::scoped_thread_local!(pub(crate) static ASSET_SERVER: AssetServer);
 scoped_tls_hkt::scoped_thread_local!(pub(crate) static QUERY_QUEUE: QueryQueue);
-scoped_tls_hkt::scoped_thread_local!(pub(crate) static SPAWNER: LocalExecutor<'static>);
+scoped_tls_hkt::scoped_thread_local!(pub(crate) static SPAWNER: AsyncExecutor);
 scoped_tls_hkt::scoped_thread_local!(pub(crate) static REACTORS: Reactors);
+scoped_tls_hkt::scoped_thread_local!(pub(crate) static CURRENT_TASK: TaskOptions);
+scoped_tls_hkt::scoped_thread_local!(pub(crate) static CURRENT_TASK_ID: u64);
+scoped_tls_hkt::scoped_thread_local!(pub(crate) static TASK_LOCALS: TaskLocals);
 
 /// Returns `true` if in async context, for diagnostics purpose only.
 pub fn in_async_context() -> bool {
@@ -46,46 +61,412 @@ pub fn in_async_context() -> bool {
 /// `!Send` resource containing a reference to an async executor,
 /// this resource can be cloned to spawn futures.
 #[derive(Debug, Default, Clone)]
-pub struct AsyncExecutor(pub(crate) Rc<async_executor::LocalExecutor<'static>>);
+pub struct AsyncExecutor(pub(crate) Rc<ExecutorInner>);
+
+#[derive(Debug, Default)]
+pub(crate) struct ExecutorInner {
+    pub(crate) high: LocalExecutor<'static>,
+    pub(crate) normal: LocalExecutor<'static>,
+    pub(crate) background: LocalExecutor<'static>,
+    /// Number of tasks currently waiting to be polled.
+    pub(crate) scheduled: Arc<AtomicUsize>,
+    /// Number of tasks left in the queue at the end of the last run.
+    pub(crate) deferred: Cell<usize>,
+    pub(crate) registry: TaskRegistry,
+    /// Panics caught since the last run.
+    pub(crate) panics: Rc<RefCell<Vec<TaskPanicked>>>,
+    /// Total number of polls.
+    pub(crate) polls: Cell<u64>,
+    /// Time spent running the executor since last read by diagnostics.
+    pub(crate) busy: Cell<Duration>,
+}
+
+type RemoteSpawn = Box<dyn FnOnce(&AsyncExecutor) + Send>;
+
+/// `Send` and `Sync` resource for spawning futures on the [`AsyncExecutor`] from any thread,
+/// e.g. from bevy's task pools or [`AsyncWorld::unblock`](crate::AsyncWorld::unblock).
+///
+/// Since futures on the executor are `!Send`, this accepts `Send` closures that create them.
+/// Closures are queued and run on the main thread in the next run of [`run_async_executor`].
+///
+/// # Example
+///
+/// ```
+/// # bevy_defer::test_spawn!({
+/// let spawner = AsyncWorld.resource::<AsyncSpawner>().cloned()?;
+/// AsyncWorld.unblock(move || {
+///     spawner.spawn(|| async {
+///         AsyncWorld.spawn_bundle(Int(4));
+///         AccessResult::Ok(())
+///     });
+/// }).await;
+/// # });
+/// ```
+#[derive(Clone, Default, Resource)]
+pub struct AsyncSpawner(Arc<Mutex<Vec<RemoteSpawn>>>);
+
+impl std::fmt::Debug for AsyncSpawner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncSpawner")
+            .field("queued", &self.0.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl AsyncSpawner {
+    /// Spawn a future created by `f`, logs errors but does not wait for it to complete.
+    #[track_caller]
+    pub fn spawn<T: 'static, E: Display + 'static, F: Future<Output = Result<T, E>> + 'static>(
+        &self,
+        f: impl FnOnce() -> F + Send + 'static,
+    ) {
+        self.spawn_with(TaskOptions::default(), f)
+    }
+
+    /// Spawn a future created by `f` with [`TaskOptions`],
+    /// logs errors but does not wait for it to complete.
+    #[track_caller]
+    pub fn spawn_with<
+        T: 'static,
+        E: Display + 'static,
+        F: Future<Output = Result<T, E>> + 'static,
+    >(
+        &self,
+        options: impl Into<TaskOptions>,
+        f: impl FnOnce() -> F + Send + 'static,
+    ) {
+        let options = options.into();
+        let location = Location::caller();
+        self.push(move |executor| {
+            executor
+                .spawn_located(options, location, async move {
+                    if let Err(e) = f().await {
+                        error!("{e}")
+                    }
+                })
+                .detach();
+        });
+    }
+
+    /// Spawn a future created by `f` and obtain its output from any thread.
+    ///
+    /// Returns `None` if the task is dropped before completion, e.g. with the executor.
+    #[track_caller]
+    pub fn spawn_task<T: Send + 'static, F: Future<Output = T> + 'static>(
+        &self,
+        f: impl FnOnce() -> F + Send + 'static,
+    ) -> impl Future<Output = Option<T>> + Send + 'static {
+        let location = Location::caller();
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        self.push(move |executor| {
+            executor
+                .spawn_located(TaskOptions::default(), location, async move {
+                    let _ = sender.send(f().await);
+                })
+                .detach();
+        });
+        async move { receiver.await.ok() }
+    }
+
+    fn push(&self, f: impl FnOnce(&AsyncExecutor) + Send + 'static) {
+        self.0.lock().unwrap().push(Box::new(f));
+    }
+
+    /// Spawn all queued futures.
+    fn spawn_queued(&self, executor: &AsyncExecutor) {
+        let queued = std::mem::take(&mut *self.0.lock().unwrap());
+        for spawn in queued {
+            spawn(executor);
+        }
+    }
+}
+
+/// Limits how much work [`run_async_executor`] can do in a single run.
+///
+/// Tasks that do not fit in the budget are not dropped,
+/// they will be polled in the next run of the executor.
+///
+/// At least one task is always polled per run, regardless of the budget.
+/// The budget does not apply to [`TaskPriority::High`] tasks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Resource)]
+pub enum ExecutorBudget {
+    /// Run until there are no more tasks to poll.
+    #[default]
+    Unlimited,
+    /// Poll at most this many tasks per run.
+    Ticks(usize),
+    /// Stop polling new tasks once this much wall-clock time has passed.
+    Duration(Duration),
+}
+
+impl ExecutorBudget {
+    fn is_exhausted(&self, ticks: usize, start: Instant) -> bool {
+        match self {
+            ExecutorBudget::Unlimited => false,
+            ExecutorBudget::Ticks(max) => ticks >= *max,
+            ExecutorBudget::Duration(max) => start.elapsed() >= *max,
+        }
+    }
+}
+
+impl ExecutorInner {
+    fn lane(&self, priority: TaskPriority) -> &LocalExecutor<'static> {
+        match priority {
+            TaskPriority::High => &self.high,
+            TaskPriority::Normal => &self.normal,
+            TaskPriority::Background => &self.background,
+        }
+    }
+}
 
 impl AsyncExecutor {
     /// Spawns a future, does not wait for it to complete.
+    #[track_caller]
     pub fn spawn_any<T: 'static>(&self, future: impl Future<Output = T> + 'static) {
-        self.0.spawn(future).detach();
+        self.spawn_any_with(TaskOptions::default(), future)
     }
 
     /// Spawns a future and returns a [`Task`].
+    #[track_caller]
     pub fn spawn_task<T: 'static>(&self, future: impl Future<Output = T> + 'static) -> Task<T> {
-        self.0.spawn(future)
+        self.spawn_task_with(TaskOptions::default(), future)
     }
 
     /// Spawns a future, logs errors but does not wait for it to complete.
+    #[track_caller]
     pub fn spawn<T: 'static, E: Display>(
         &self,
         future: impl Future<Output = Result<T, E>> + 'static,
     ) {
+        self.spawn_with(TaskOptions::default(), future)
+    }
+
+    /// Spawns a future with [`TaskOptions`], does not wait for it to complete.
+    #[track_caller]
+    pub fn spawn_any_with<T: 'static>(
+        &self,
+        options: impl Into<TaskOptions>,
+        future: impl Future<Output = T> + 'static,
+    ) {
+        self.spawn_task_with(options, future).detach();
+    }
+
+    /// Spawns a future with [`TaskOptions`] and returns a [`Task`].
+    #[track_caller]
+    pub fn spawn_task_with<T: 'static>(
+        &self,
+        options: impl Into<TaskOptions>,
+        future: impl Future<Output = T> + 'static,
+    ) -> Task<T> {
+        self.spawn_located(options.into(), Location::caller(), future)
+    }
+
+    /// Spawns a future with [`TaskOptions`], logs errors but does not wait for it to complete.
+    #[track_caller]
+    pub fn spawn_with<T: 'static, E: Display>(
+        &self,
+        options: impl Into<TaskOptions>,
+        future: impl Future<Output = Result<T, E>> + 'static,
+    ) {
+        self.spawn_any_with(options, async {
+            if let Err(e) = future.await {
+                error!("{e}")
+            }
+        });
+    }
+
+    pub(crate) fn spawn_located<T: 'static>(
+        &self,
+        options: TaskOptions,
+        location: &'static Location<'static>,
+        future: impl Future<Output = T> + 'static,
+    ) -> Task<T> {
+        let executor = self.0.lane(options.priority);
+        let locals = if options.inherit_locals {
+            TaskLocals::inherit()
+        } else {
+            TaskLocals::default()
+        };
+        let task = self.0.registry.register(options, location);
+        executor.spawn(tracked(future, &self.0, task, locals))
+    }
+
+    /// Returns the [`TaskRegistry`] of this executor.
+    pub fn task_registry(&self) -> &TaskRegistry {
+        &self.0.registry
+    }
+
+    /// Returns the number of tasks that were ready to be polled
+    /// but were deferred due to [`ExecutorBudget`] in the last run.
+    pub fn deferred_tasks(&self) -> usize {
+        self.0.deferred.get()
+    }
+
+    /// Poll tasks until either no task can make progress or the budget is exhausted.
+    ///
+    /// [`TaskPriority::High`] tasks are always polled before other tasks and are not
+    /// limited by the budget.
+    pub(crate) fn run(&self, budget: ExecutorBudget) {
+        let start = Instant::now();
+        let mut ticks = 0;
+        let mut polls = 0;
+        loop {
+            while self.0.high.try_tick() {
+                polls += 1;
+            }
+            if ticks > 0 && budget.is_exhausted(ticks, start) {
+                break;
+            }
+            if self.0.normal.try_tick() || self.0.background.try_tick() {
+                ticks += 1;
+                polls += 1;
+            } else {
+                break;
+            }
+        }
         self.0
-            .spawn(async {
-                if let Err(e) = future.await {
-                    error!("{e}")
+            .deferred
+            .set(self.0.scheduled.load(Ordering::Acquire));
+        self.0.polls.set(self.0.polls.get() + polls);
+        self.0.busy.set(self.0.busy.get() + start.elapsed());
+    }
+}
+
+/// Waker that keeps track of whether a task is in the executor's queue.
+#[derive(Debug)]
+struct ScheduleTracker {
+    scheduled: AtomicBool,
+    count: Arc<AtomicUsize>,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl ScheduleTracker {
+    fn mark_scheduled(&self) {
+        if !self.scheduled.swap(true, Ordering::AcqRel) {
+            self.count.fetch_add(1, Ordering::AcqRel);
+        }
+    }
+
+    fn unmark_scheduled(&self) {
+        if self.scheduled.swap(false, Ordering::AcqRel) {
+            self.count.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+impl Wake for ScheduleTracker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.mark_scheduled();
+        if let Some(waker) = self.waker.lock().unwrap().as_ref() {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+/// Unmarks the task on drop so cancelled tasks are not counted.
+#[derive(Debug)]
+struct TrackerGuard(Arc<ScheduleTracker>, RegisteredTask);
+
+impl Drop for TrackerGuard {
+    fn drop(&mut self) {
+        self.0.unmark_scheduled();
+    }
+}
+
+/// Wraps a future so the number of scheduled tasks can be counted and panics can be reported.
+fn tracked<T>(
+    future: impl Future<Output = T> + 'static,
+    executor: &ExecutorInner,
+    task: RegisteredTask,
+    locals: TaskLocals,
+) -> impl Future<Output = T> + 'static {
+    // Tasks are scheduled immediately on spawn.
+    executor.scheduled.fetch_add(1, Ordering::AcqRel);
+    let panics = executor.panics.clone();
+    let guard = TrackerGuard(
+        Arc::new(ScheduleTracker {
+            scheduled: AtomicBool::new(true),
+            count: executor.scheduled.clone(),
+            waker: Mutex::new(None),
+        }),
+        task,
+    );
+    async move {
+        let tracker = &guard.0;
+        let waker = Waker::from(tracker.clone());
+        let mut future = pin!(future);
+        poll_fn(|cx| {
+            tracker.unmark_scheduled();
+            let options = guard.1.options();
+            if !options.tags.is_empty()
+                && QUERY_QUEUE.is_set()
+                && QUERY_QUEUE.with(|queue| queue.poll_paused(&options.tags, cx))
+            {
+                return Poll::Pending;
+            }
+            let id = guard.1.id();
+            if QUERY_QUEUE.is_set() && QUERY_QUEUE.with(|queue| queue.poll_gated(id, cx)) {
+                return Poll::Pending;
+            }
+            guard.1.polled();
+            {
+                let mut inner = tracker.waker.lock().unwrap();
+                match inner.as_ref() {
+                    Some(w) if w.will_wake(cx.waker()) => (),
+                    _ => *inner = Some(cx.waker().clone()),
                 }
-            })
-            .detach();
+            }
+            let mut cx = Context::from_waker(&waker);
+            let poll = || {
+                CURRENT_TASK_ID.set(&id, || {
+                    CURRENT_TASK.set(options, || {
+                        TASK_LOCALS.set(&locals, || future.as_mut().poll(&mut cx))
+                    })
+                })
+            };
+            match catch_unwind(AssertUnwindSafe(poll)) {
+                Ok(poll) => poll,
+                Err(payload) => {
+                    panics.borrow_mut().push(guard.1.panicked(payload.as_ref()));
+                    resume_unwind(payload)
+                }
+            }
+        })
+        .await
     }
 }
 
 /// System for running [`AsyncExecutor`].
 pub fn run_async_executor(world: &mut World) {
+    let budget = world
+        .get_resource::<ExecutorBudget>()
+        .copied()
+        .unwrap_or_default();
+    run_async_executor_with(world, budget)
+}
+
+/// Run the [`AsyncExecutor`] with a budget.
+pub(crate) fn run_async_executor_with(world: &mut World, budget: ExecutorBudget) {
     let reactors = world.resource::<Reactors>().clone();
     let queue = world.non_send::<QueryQueue>().clone();
     let executor = world.non_send::<AsyncExecutor>().clone();
     let assets = world.get_resource::<AssetServer>().cloned();
+    let spawner = world.get_resource::<AsyncSpawner>().cloned();
 
     let mut f = || {
-        SPAWNER.set(&executor.0.clone(), || {
+        SPAWNER.set(&executor, || {
             QUERY_QUEUE.set(&queue, || {
                 REACTORS.set(&reactors, || {
-                    WORLD.set(world, || while executor.0.try_tick() {});
+                    WORLD.set(world, || {
+                        if let Some(spawner) = &spawner {
+                            spawner.spawn_queued(&executor);
+                        }
+                        executor.run(budget)
+                    });
                 })
             })
         })
@@ -96,4 +477,17 @@ pub fn run_async_executor(world: &mut World) {
     } else {
         f()
     }
+
+    let panics = executor.0.panics.take();
+    if panics.is_empty() {
+        return;
+    }
+    let hook = world.get_resource::<TaskPanicHook>().cloned();
+    for panic in panics {
+        error!("{panic}");
+        if let Some(hook) = &hook {
+            (hook.0)(&panic);
+        }
+        world.write_message(panic);
+    }
 }
@@ -2,10 +2,11 @@ use bevy::asset::{Asset, AssetId, Handle};
 use bevy::ecs::{
     prelude::{Component, Entity, Resource},
     query::{QueryData, QueryFilter},
+    relationship::RelationshipTarget,
 };
 use std::borrow::Borrow;
 
-use crate::access::AsyncEntity;
+use crate::access::{AsyncEntity, EntityPath, VirtualEntity};
 use crate::{
     access::{AsyncAsset, AsyncComponent, AsyncEntityQuery, AsyncQuery, AsyncResource},
     AsyncWorld,
@@ -18,11 +19,12 @@ use crate::{
 /// * `fetch!(entity, Type)`
 ///
 /// Obtain a [`struct@AsyncComponent`] or [`AsyncEntityQuery`] depend on the type.
-/// `entity` can be [`Entity`] or [`AsyncEntity`].
+/// `entity` can be [`Entity`], [`AsyncEntity`], [`EntityPath`] or [`AsyncEntity<EntityPath>`].
 ///
 /// * `fetch!(#expr)`
 ///
-/// Obtain a [`AsyncEntity`] or [`AsyncAsset`] from an underlying expr.
+/// Obtain a [`AsyncEntity`] or [`AsyncAsset`] from an underlying expr,
+/// an [`EntityPath`] becomes an [`AsyncEntity<EntityPath>`].
 ///
 /// * `fetch!(Type)`
 ///
@@ -36,10 +38,10 @@ macro_rules! fetch {
         $crate::fetch1(&$expr)
     };
     ($entity: expr, $comp: ty $(,)?) => {
-        $crate::fetch::<$comp, _>(&$entity)
+        $crate::fetch::<$comp, _, _>(&$entity)
     };
     ($entity: expr, $data: ty, $filter: ty $(,)?) => {
-        $crate::fetch2::<$data, $filter>(&$entity)
+        $crate::fetch2::<$data, $filter, _>(&$entity)
     };
 }
 
@@ -55,6 +57,22 @@ pub trait FetchEntity<M = ()> {
     fn fetch(entity: &impl Borrow<Entity>) -> Self::Out;
 }
 
+/// [`FetchEntity`] on a [`VirtualEntity`] like [`EntityPath`],
+/// implemented for all [`FetchEntity`] types on [`Entity`].
+pub trait FetchVirtualEntity<M, E: VirtualEntity> {
+    type Out;
+
+    fn fetch_virtual(entity: E) -> Self::Out;
+}
+
+impl<T: FetchEntity<M>, M> FetchVirtualEntity<M, Entity> for T {
+    type Out = T::Out;
+
+    fn fetch_virtual(entity: Entity) -> Self::Out {
+        T::fetch(&entity)
+    }
+}
+
 pub trait FetchOne<M = ()> {
     type Out;
 
@@ -91,6 +109,53 @@ impl<T: QueryData, F: QueryFilter> FetchWorld<QueryFilteredMarker> for (T, F) {
     }
 }
 
+/// An [`Entity`] or a [`VirtualEntity`] accepted by [`fetch!`].
+pub trait FetchTarget {
+    type Entity: VirtualEntity;
+
+    fn fetch_target(&self) -> Self::Entity;
+}
+
+impl<T: Borrow<Entity>> FetchTarget for T {
+    type Entity = Entity;
+
+    fn fetch_target(&self) -> Self::Entity {
+        *self.borrow()
+    }
+}
+
+impl<R: RelationshipTarget> FetchTarget for EntityPath<R> {
+    type Entity = EntityPath<R>;
+
+    fn fetch_target(&self) -> Self::Entity {
+        self.clone()
+    }
+}
+
+impl<R: RelationshipTarget> FetchTarget for &EntityPath<R> {
+    type Entity = EntityPath<R>;
+
+    fn fetch_target(&self) -> Self::Entity {
+        (*self).clone()
+    }
+}
+
+impl<R: RelationshipTarget> FetchTarget for AsyncEntity<EntityPath<R>> {
+    type Entity = EntityPath<R>;
+
+    fn fetch_target(&self) -> Self::Entity {
+        self.0.clone()
+    }
+}
+
+impl<R: RelationshipTarget> FetchTarget for &AsyncEntity<EntityPath<R>> {
+    type Entity = EntityPath<R>;
+
+    fn fetch_target(&self) -> Self::Entity {
+        self.0.clone()
+    }
+}
+
 impl<T: Component> FetchEntity<ComponentMarker> for T {
     type Out = AsyncComponent<T>;
 
@@ -107,11 +172,27 @@ impl<T: QueryData> FetchEntity<QueryMarker> for T {
     }
 }
 
-impl<T: Borrow<Entity>> FetchOne<ComponentMarker> for T {
-    type Out = AsyncEntity;
+impl<T: Component, R: RelationshipTarget> FetchVirtualEntity<ComponentMarker, EntityPath<R>> for T {
+    type Out = AsyncComponent<T, EntityPath<R>>;
+
+    fn fetch_virtual(entity: EntityPath<R>) -> Self::Out {
+        AsyncEntity(entity).component::<T>()
+    }
+}
+
+impl<T: QueryData, R: RelationshipTarget> FetchVirtualEntity<QueryMarker, EntityPath<R>> for T {
+    type Out = AsyncEntityQuery<T, (), EntityPath<R>>;
+
+    fn fetch_virtual(entity: EntityPath<R>) -> Self::Out {
+        AsyncEntity(entity).query::<T>()
+    }
+}
+
+impl<T: FetchTarget> FetchOne<ComponentMarker> for T {
+    type Out = AsyncEntity<T::Entity>;
 
     fn fetch(&self) -> Self::Out {
-        AsyncWorld.entity(*self.borrow())
+        AsyncEntity(self.fetch_target())
     }
 }
 
@@ -163,17 +244,19 @@ pub fn fetch1<T: FetchOne<M>, M>(item: &T) -> T::Out {
     T::fetch(item)
 }
 
-pub fn fetch2<Q: QueryData, F: QueryFilter>(entity: impl Borrow<Entity>) -> AsyncEntityQuery<Q, F> {
-    AsyncWorld.entity(*entity.borrow()).query_filtered::<Q, F>()
+pub fn fetch2<Q: QueryData, F: QueryFilter, E: FetchTarget>(
+    entity: &E,
+) -> AsyncEntityQuery<Q, F, E::Entity> {
+    AsyncEntity(entity.fetch_target()).query_filtered::<Q, F>()
 }
 
-pub fn fetch<T: FetchEntity<M>, M>(entity: &impl Borrow<Entity>) -> T::Out {
-    T::fetch(entity)
+pub fn fetch<T: FetchVirtualEntity<M, E::Entity>, M, E: FetchTarget>(entity: &E) -> T::Out {
+    T::fetch_virtual(entity.fetch_target())
 }
 
 #[cfg(test)]
 mod text {
-    use crate::access::AsyncAsset;
+    use crate::access::{AsyncAsset, EntityPath};
     use crate::AsyncWorld;
     use bevy::asset::{AssetId, Handle};
     use bevy::diagnostic::SystemInfo;
@@ -193,6 +276,18 @@ mod text {
         let _b = fetch!(#e2);
         let _c = fetch!(#e3);
         let _d = fetch!(#e4);
+        let p1 = EntityPath::new("Player/Hand.R");
+        let p2 = &p1;
+        let p3 = AsyncWorld.entity_path("Player/Hand.R");
+        let p4 = &p3;
+        let _a = fetch!(p1, Transform);
+        let _b = fetch!(p2, &Transform);
+        let _c = fetch!(p3, (&Transform, &GlobalTransform));
+        let _d = fetch!(p4, &Transform, With<GlobalTransform>);
+        let _a = fetch!(#p1);
+        let _b = fetch!(#p2);
+        let _c = fetch!(#p3);
+        let _d = fetch!(#p4);
         let _a = fetch!(SystemInfo);
         let _b = fetch!(&Transform);
         let _c = fetch!((&Transform, &GlobalTransform));
@@ -0,0 +1,219 @@
+//! Streams driven by game time.
+use futures::stream::{unfold, FusedStream};
+use futures::{FutureExt, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use crate::sync::oneshot::MaybeChannelOut;
+use crate::tween::AsSeconds;
+use crate::AsyncWorld;
+
+impl AsyncWorld {
+    /// Create a `Stream` that yields once every `period`,
+    /// according to the same clock as [`AsyncWorld::sleep_until`].
+    ///
+    /// The first item is yielded after `period`.
+    /// If the stream is not polled in time, missed items are yielded once, not repeated.
+    ///
+    /// # Panics
+    ///
+    /// * If used outside a `bevy_defer` future.
+    /// * If `period` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # use futures::StreamExt;
+    /// let mut interval = AsyncWorld.interval(0.1);
+    /// for _ in 0..3 {
+    ///     interval.next().await;
+    /// }
+    /// # });
+    /// ```
+    pub fn interval(&self, period: impl AsSeconds) -> impl FusedStream<Item = ()> + Unpin {
+        let period = period.as_duration();
+        if period.is_zero() {
+            panic!("AsyncWorld::interval must have a non-zero period.")
+        }
+        unfold(self.now() + period, move |next| {
+            AsyncWorld
+                .sleep_until(next)
+                .map(move |_| Some(((), next.max(AsyncWorld.now()) + period)))
+        })
+    }
+
+    /// Create a `Stream` that yields once every `frames` frames,
+    /// according to the same frame count as [`AsyncWorld::sleep_frames`].
+    ///
+    /// # Panics
+    ///
+    /// * If used outside a `bevy_defer` future.
+    /// * If `frames` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # use futures::StreamExt;
+    /// let mut interval = AsyncWorld.interval_frames(2);
+    /// for _ in 0..3 {
+    ///     interval.next().await;
+    /// }
+    /// # });
+    /// ```
+    pub fn interval_frames(&self, frames: u32) -> impl FusedStream<Item = ()> + Unpin {
+        if frames == 0 {
+            panic!("AsyncWorld::interval_frames must have a non-zero period.")
+        }
+        unfold((), move |_| {
+            AsyncWorld.sleep_frames(frames).map(|_| Some(((), ())))
+        })
+    }
+}
+
+/// Extension for rate limiting `Stream`s with game time,
+/// e.g. [`AsyncWorld::state_stream`] or signal streams.
+///
+/// Timers use the same clock as [`AsyncWorld::sleep`].
+pub trait StreamTimeExt: Stream + Unpin + Sized {
+    /// Yield an item, then drop items until `duration` has passed.
+    ///
+    /// # Panics
+    ///
+    /// If polled outside a `bevy_defer` future.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # use futures::StreamExt;
+    /// let mut refresh = AsyncWorld.interval_frames(1).throttle(0.5);
+    /// refresh.next().await;
+    /// # });
+    /// ```
+    fn throttle(self, duration: impl AsSeconds) -> Throttle<Self> {
+        Throttle {
+            stream: self,
+            duration: duration.as_duration(),
+            timer: None,
+        }
+    }
+
+    /// Yield the latest item only after no new items arrive for `duration`.
+    ///
+    /// The latest item is yielded immediately when the stream ends.
+    ///
+    /// # Panics
+    ///
+    /// If polled outside a `bevy_defer` future.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # use futures::StreamExt;
+    /// let mut settled = futures::stream::iter([1, 2, 3]).debounce(0.5);
+    /// assert_eq!(settled.next().await, Some(3));
+    /// # });
+    /// ```
+    fn debounce(self, duration: impl AsSeconds) -> Debounce<Self> {
+        Debounce {
+            stream: self,
+            duration: duration.as_duration(),
+            pending: None,
+            timer: None,
+            done: false,
+        }
+    }
+}
+
+impl<S: Stream + Unpin> StreamTimeExt for S {}
+
+/// Maximum number of items read from the source in a single poll,
+/// so a source that is always ready does not block the executor.
+const MAX_ITEMS_PER_POLL: usize = 64;
+
+/// Stream for [`StreamTimeExt::throttle`].
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Throttle<S> {
+    stream: S,
+    duration: Duration,
+    timer: Option<MaybeChannelOut<()>>,
+}
+
+impl<S: Stream + Unpin> Stream for Throttle<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if let Some(timer) = &mut this.timer {
+            if timer.poll_unpin(cx).is_ready() {
+                this.timer = None;
+            }
+        }
+        for _ in 0..MAX_ITEMS_PER_POLL {
+            match this.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) if this.timer.is_none() => {
+                    this.timer = Some(AsyncWorld.sleep(this.duration));
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(Some(_)) => continue,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        AsyncWorld.yield_now_cx(cx);
+        Poll::Pending
+    }
+}
+
+/// Stream for [`StreamTimeExt::debounce`].
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Debounce<S: Stream> {
+    stream: S,
+    duration: Duration,
+    pending: Option<S::Item>,
+    timer: Option<MaybeChannelOut<()>>,
+    done: bool,
+}
+
+impl<S: Stream + Unpin> Unpin for Debounce<S> {}
+
+impl<S: Stream + Unpin> Stream for Debounce<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let mut polled = 0;
+        while !this.done {
+            if polled == MAX_ITEMS_PER_POLL {
+                AsyncWorld.yield_now_cx(cx);
+                break;
+            }
+            polled += 1;
+            match this.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => {
+                    this.pending = Some(item);
+                    this.timer = Some(AsyncWorld.sleep(this.duration));
+                }
+                Poll::Ready(None) => this.done = true,
+                Poll::Pending => break,
+            }
+        }
+        if this.done {
+            this.timer = None;
+            return Poll::Ready(this.pending.take());
+        }
+        if let Some(timer) = &mut this.timer {
+            if timer.poll_unpin(cx).is_ready() {
+                this.timer = None;
+                return Poll::Ready(this.pending.take());
+            }
+        }
+        Poll::Pending
+    }
+}
@@ -1,7 +1,7 @@
 #![doc=include_str!("../README.md")]
 #![allow(clippy::type_complexity)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
-use bevy::app::{App, First, Plugin, PostUpdate, PreUpdate, Update};
+use bevy::app::{App, First, FixedUpdate, Plugin, PostUpdate, PreUpdate, Update};
 use bevy::ecs::component::Component;
 use bevy::ecs::intern::Interned;
 use bevy::ecs::message::Message;
@@ -15,11 +15,16 @@ use bevy::state::prelude::State;
 use bevy::state::state::States;
 use bevy::time::TimeSystems;
 use std::fmt::Formatter;
+use std::panic::Location;
+use std::sync::Arc;
+use std::time::Duration;
 use std::{any::type_name, pin::Pin};
 
 pub mod access;
+mod bridge;
 pub mod cancellation;
 mod commands;
+mod diagnostics;
 mod entity_commands;
 mod errors;
 mod event;
@@ -27,13 +32,25 @@ mod executor;
 pub mod ext;
 mod fetch;
 mod inspect;
+mod interval;
+mod panics;
 mod queue;
+mod registry;
+mod scope;
 pub use inspect::{EntityInspectors, InspectEntity};
+pub use interval::{Debounce, StreamTimeExt, Throttle};
 pub mod reactors;
 pub mod signals;
 mod spawn;
+mod supervisor;
 pub(crate) mod sync;
+mod task_local;
+pub mod testing;
+mod timeline;
+mod timeout;
 pub mod tween;
+mod unblock;
+mod watch;
 pub use access::async_asset::AssetSet;
 pub use access::async_world::AsyncWorld;
 pub use access::query::{OwnedQueryState, OwnedReadonlyQueryState};
@@ -44,21 +61,34 @@ use bevy::ecs::{
     world::World,
 };
 use bevy::reflect::std_traits::ReflectDefault;
+pub use bridge::AsyncWorlds;
+pub use diagnostics::AsyncDiagnosticsPlugin;
 pub use errors::AccessError;
 pub use event::EventChannel;
-pub use executor::{in_async_context, AsyncExecutor};
+pub use executor::{in_async_context, AsyncExecutor, AsyncSpawner, ExecutorBudget};
 #[doc(hidden)]
-pub use fetch::{fetch, fetch0, fetch1, fetch2, FetchEntity, FetchOne, FetchWorld};
+pub use fetch::{
+    fetch, fetch0, fetch1, fetch2, FetchEntity, FetchOne, FetchTarget, FetchVirtualEntity,
+    FetchWorld,
+};
+pub use panics::{TaskPanicHook, TaskPanicked};
 pub use queue::LoopForFrameData;
 pub use queue::QueryQueue;
 use reactors::Reactors;
-pub use spawn::ScopedTasks;
+pub use registry::{TaskInfo, TaskOptions, TaskPriority, TaskRegistry};
+pub use scope::{Scope, ScopeOutput};
+pub use spawn::{EntityScopedTasks, ScopedTasks};
+pub use supervisor::RestartPolicy;
+pub use task_local::TaskLocalKey;
+pub use timeline::Timeline;
+pub use timeout::TimeoutExt;
+pub use unblock::{Unblock, UnblockContext};
 
 /// Systems in `bevy_defer`.
 pub mod systems {
     pub use crate::event::react_to_message;
     pub use crate::executor::run_async_executor;
-    pub use crate::queue::{run_fixed_queue, run_time_series, run_watch_queries};
+    pub use crate::queue::{run_clocks, run_fixed_queue, run_time_series, run_watch_queries};
     pub use crate::reactors::{react_to_component_change, react_to_state};
 
     #[cfg(feature = "bevy_animation")]
@@ -102,15 +132,21 @@ pub struct CoreAsyncPlugin;
 
 impl Plugin for CoreAsyncPlugin {
     fn build(&self, app: &mut App) {
-        app.init_non_send::<QueryQueue>()
-            .init_non_send::<AsyncExecutor>()
+        let executor = AsyncExecutor::default();
+        app.insert_resource(executor.task_registry().clone())
+            .init_resource::<AsyncSpawner>()
+            .insert_non_send(executor)
+            .init_non_send::<QueryQueue>()
             .init_non_send::<QueryCache>()
             .init_resource::<Reactors>()
             .init_resource::<EntityInspectors>()
+            .init_resource::<ExecutorBudget>()
+            .add_message::<TaskPanicked>()
             .register_type::<Signals>()
             .register_type_data::<Signals, ReflectDefault>()
             .init_schedule(BeforeAsyncExecutor)
             .add_systems(First, systems::run_time_series.after(TimeSystems))
+            .add_systems(First, systems::run_clocks.after(TimeSystems))
             .add_systems(Update, run_fixed_queue)
             .add_systems(BeforeAsyncExecutor, systems::run_watch_queries);
 
@@ -142,6 +178,9 @@ pub fn run_before_async_executor(world: &mut World) {
 #[derive(Debug)]
 pub struct AsyncPlugin {
     schedules: Vec<(Interned<dyn ScheduleLabel>, Option<Interned<dyn SystemSet>>)>,
+    budget: Option<ExecutorBudget>,
+    panic_hook: Option<TaskPanicHook>,
+    hooks: Vec<Interned<dyn ScheduleLabel>>,
 }
 
 impl AsyncPlugin {
@@ -151,6 +190,9 @@ impl AsyncPlugin {
     pub fn empty() -> Self {
         AsyncPlugin {
             schedules: Vec::new(),
+            budget: None,
+            panic_hook: None,
+            hooks: Vec::new(),
         }
     }
 
@@ -161,6 +203,9 @@ impl AsyncPlugin {
     pub fn default_settings() -> Self {
         AsyncPlugin {
             schedules: vec![(Interned(Box::leak(Box::new(Update))), None)],
+            budget: None,
+            panic_hook: None,
+            hooks: Vec::new(),
         }
     }
 
@@ -172,6 +217,9 @@ impl AsyncPlugin {
                 (Interned(Box::leak(Box::new(Update))), None),
                 (Interned(Box::leak(Box::new(PostUpdate))), None),
             ],
+            budget: None,
+            panic_hook: None,
+            hooks: Vec::new(),
         }
     }
 }
@@ -192,6 +240,39 @@ impl AsyncPlugin {
         ));
         self
     }
+
+    /// Poll at most `ticks` tasks per run of the executor.
+    ///
+    /// Remaining tasks are deferred to the next run,
+    /// see [`AsyncExecutor::deferred_tasks`].
+    pub fn with_tick_budget(mut self, ticks: usize) -> Self {
+        self.budget = Some(ExecutorBudget::Ticks(ticks));
+        self
+    }
+
+    /// Stop polling tasks once `duration` has passed in a run of the executor.
+    ///
+    /// Remaining tasks are deferred to the next run,
+    /// see [`AsyncExecutor::deferred_tasks`].
+    pub fn with_time_budget(mut self, duration: Duration) -> Self {
+        self.budget = Some(ExecutorBudget::Duration(duration));
+        self
+    }
+
+    /// Allow [`AsyncWorld::next_schedule`] to resume futures in a `Schedule`.
+    ///
+    /// This runs the executor in the schedule only if a future is waiting on it,
+    /// and only polls the tasks of these futures.
+    pub fn with_schedule_hook(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.hooks.push(schedule.intern());
+        self
+    }
+
+    /// Call a function when a task panics, before the [`TaskPanicked`] message is written.
+    pub fn on_panic(mut self, f: impl Fn(&TaskPanicked) + Send + Sync + 'static) -> Self {
+        self.panic_hook = Some(TaskPanicHook(Arc::new(f)));
+        self
+    }
 }
 
 impl Plugin for AsyncPlugin {
@@ -200,6 +281,16 @@ impl Plugin for AsyncPlugin {
         if !app.is_plugin_added::<CoreAsyncPlugin>() {
             app.add_plugins(CoreAsyncPlugin);
         }
+        if let Some(budget) = self.budget {
+            app.insert_resource(budget);
+        }
+        if let Some(hook) = &self.panic_hook {
+            app.insert_resource(hook.clone());
+        }
+        queue::add_schedule_hook(app.world_mut(), FixedUpdate.intern());
+        for schedule in &self.hooks {
+            queue::add_schedule_hook(app.world_mut(), *schedule);
+        }
         for (schedule, set) in &self.schedules {
             if let Some(set) = set {
                 app.add_systems(
@@ -232,6 +323,13 @@ pub trait AsyncExtension {
     /// Spawn a task to be run on the [`AsyncExecutor`].
     fn spawn_task(&mut self, f: impl Future<Output = AccessResult> + 'static) -> &mut Self;
 
+    /// Spawn a task with [`TaskOptions`] to be run on the [`AsyncExecutor`].
+    fn spawn_task_with(
+        &mut self,
+        options: impl Into<TaskOptions>,
+        f: impl Future<Output = AccessResult> + 'static,
+    ) -> &mut Self;
+
     /// Spawn a `bevy_defer` compatible future, the future is constrained to a [`States`]
     /// and will be cancelled upon exiting the state.
     ///
@@ -280,34 +378,29 @@ pub trait AsyncExtension {
 }
 
 impl AsyncExtension for World {
+    #[track_caller]
     fn spawn_task(&mut self, f: impl Future<Output = AccessResult> + 'static) -> &mut Self {
         self.non_send::<AsyncExecutor>().spawn(f);
         self
     }
 
+    #[track_caller]
+    fn spawn_task_with(
+        &mut self,
+        options: impl Into<TaskOptions>,
+        f: impl Future<Output = AccessResult> + 'static,
+    ) -> &mut Self {
+        self.non_send::<AsyncExecutor>().spawn_with(options, f);
+        self
+    }
+
+    #[track_caller]
     fn spawn_state_scoped<S: States>(
         &mut self,
         state: S,
         fut: impl Future<Output = AccessResult> + 'static,
     ) -> AccessResult {
-        match self.get_resource::<State<S>>() {
-            Some(s) if s.get() == &state => (),
-            _ => {
-                return Err(AccessError::NotInState {
-                    ty: type_name::<S>(),
-                })
-            }
-        };
-        let task = self.non_send::<AsyncExecutor>().spawn_task(fut);
-        if let Some(mut res) = self.get_resource_mut::<ScopedTasks<S>>() {
-            res.tasks.entry(state).or_default().push(task);
-        } else {
-            error!(
-                "Cannot spawn state scoped futures without `react_to_state::<{}>`.",
-                type_name::<S>()
-            )
-        }
-        Ok(())
+        spawn_state_scoped_at(self, state, fut, Location::caller())
     }
 
     fn register_inspect_entity_by_component<C: Component>(
@@ -338,12 +431,55 @@ impl AsyncExtension for World {
     }
 }
 
+fn spawn_state_scoped_at<S: States>(
+    world: &mut World,
+    state: S,
+    fut: impl Future<Output = AccessResult> + 'static,
+    location: &'static Location<'static>,
+) -> AccessResult {
+    match world.get_resource::<State<S>>() {
+        Some(s) if s.get() == &state => (),
+        _ => {
+            return Err(AccessError::NotInState {
+                ty: type_name::<S>(),
+            })
+        }
+    };
+    let task =
+        world
+            .non_send::<AsyncExecutor>()
+            .spawn_located(TaskOptions::default(), location, fut);
+    if let Some(mut res) = world.get_resource_mut::<ScopedTasks<S>>() {
+        res.tasks.entry(state).or_default().push(task);
+    } else {
+        error!(
+            "Cannot spawn state scoped futures without `react_to_state::<{}>`.",
+            type_name::<S>()
+        )
+    }
+    Ok(())
+}
+
 impl AsyncExtension for App {
+    #[track_caller]
     fn spawn_task(&mut self, f: impl Future<Output = AccessResult> + 'static) -> &mut Self {
         self.world().non_send::<AsyncExecutor>().spawn(f);
         self
     }
 
+    #[track_caller]
+    fn spawn_task_with(
+        &mut self,
+        options: impl Into<TaskOptions>,
+        f: impl Future<Output = AccessResult> + 'static,
+    ) -> &mut Self {
+        self.world()
+            .non_send::<AsyncExecutor>()
+            .spawn_with(options, f);
+        self
+    }
+
+    #[track_caller]
     fn spawn_state_scoped<S: States>(
         &mut self,
         state: S,
@@ -433,6 +569,13 @@ pub trait AsyncCommandsExtension {
         f: impl FnOnce() -> F + Send + 'static,
     ) -> &mut Self;
 
+    /// Spawn a task with [`TaskOptions`] to be run on the [`AsyncExecutor`].
+    fn spawn_task_with<F: Future<Output = AccessResult> + 'static>(
+        &mut self,
+        options: impl Into<TaskOptions>,
+        f: impl FnOnce() -> F + Send + 'static,
+    ) -> &mut Self;
+
     /// Spawn a `bevy_defer` compatible future, the future is constrained to a [`States`]
     /// and will be cancelled upon exiting the state.
     ///
@@ -447,14 +590,26 @@ pub trait AsyncCommandsExtension {
 }
 
 impl AsyncCommandsExtension for Commands<'_, '_> {
+    #[track_caller]
     fn spawn_task<F: Future<Output = AccessResult> + 'static>(
         &mut self,
         f: impl (FnOnce() -> F) + Send + 'static,
     ) -> &mut Self {
-        self.queue(SpawnFn::new(f));
+        self.queue(SpawnFn::new(TaskOptions::default(), f));
+        self
+    }
+
+    #[track_caller]
+    fn spawn_task_with<F: Future<Output = AccessResult> + 'static>(
+        &mut self,
+        options: impl Into<TaskOptions>,
+        f: impl (FnOnce() -> F) + Send + 'static,
+    ) -> &mut Self {
+        self.queue(SpawnFn::new(options.into(), f));
         self
     }
 
+    #[track_caller]
     fn spawn_state_scoped<S: States, F: Future<Output = AccessResult> + 'static>(
         &mut self,
         state: S,
@@ -484,6 +639,13 @@ pub trait AsyncEntityCommandsExtension {
         f: impl FnOnce(Entity) -> F + Send + 'static,
     ) -> &mut Self;
 
+    /// Spawn a task with [`TaskOptions`] to be run on the [`AsyncExecutor`].
+    fn spawn_task_with<F: Future<Output = AccessResult> + 'static>(
+        &mut self,
+        options: impl Into<TaskOptions>,
+        f: impl FnOnce(Entity) -> F + Send + 'static,
+    ) -> &mut Self;
+
     /// Spawn a `bevy_defer` compatible future, the future is constrained to a [`States`]
     /// and will be cancelled upon exiting the state.
     ///
@@ -495,18 +657,42 @@ pub trait AsyncEntityCommandsExtension {
         state: S,
         fut: impl FnOnce(Entity) -> F + Send + 'static,
     ) -> &mut Self;
+
+    /// Spawn a `bevy_defer` compatible future, the future is constrained to this entity
+    /// and will be cancelled when the entity is despawned or [`EntityScopedTasks`] is removed.
+    ///
+    /// Errors returned by the future are logged.
+    fn spawn_entity_scoped<F: Future<Output = AccessResult> + 'static>(
+        &mut self,
+        fut: impl FnOnce(Entity) -> F + Send + 'static,
+    ) -> &mut Self;
 }
 
 impl AsyncEntityCommandsExtension for EntityCommands<'_> {
+    #[track_caller]
     fn spawn_task<F: Future<Output = AccessResult> + 'static>(
         &mut self,
         f: impl (FnOnce(Entity) -> F) + Send + 'static,
     ) -> &mut Self {
         let entity = self.id();
-        self.commands().queue(SpawnFn::new(move || f(entity)));
+        self.commands()
+            .queue(SpawnFn::new(TaskOptions::default(), move || f(entity)));
         self
     }
 
+    #[track_caller]
+    fn spawn_task_with<F: Future<Output = AccessResult> + 'static>(
+        &mut self,
+        options: impl Into<TaskOptions>,
+        f: impl (FnOnce(Entity) -> F) + Send + 'static,
+    ) -> &mut Self {
+        let entity = self.id();
+        self.commands()
+            .queue(SpawnFn::new(options.into(), move || f(entity)));
+        self
+    }
+
+    #[track_caller]
     fn spawn_state_scoped<S: States, F: Future<Output = AccessResult> + 'static>(
         &mut self,
         state: S,
@@ -517,18 +703,37 @@ impl AsyncEntityCommandsExtension for EntityCommands<'_> {
             .queue(StateScopedSpawnFn::new(state, move || f(entity)));
         self
     }
+
+    #[track_caller]
+    fn spawn_entity_scoped<F: Future<Output = AccessResult> + 'static>(
+        &mut self,
+        f: impl FnOnce(Entity) -> F + Send + 'static,
+    ) -> &mut Self {
+        let entity = self.id();
+        self.commands()
+            .queue(EntityScopedSpawnFn::new(entity, move || f(entity)));
+        self
+    }
 }
 
 /// [`Command`] for spawning a task.
-pub struct SpawnFn(
-    Box<dyn (FnOnce() -> Pin<Box<dyn Future<Output = AccessResult>>>) + Send + 'static>,
-);
+pub struct SpawnFn {
+    future: Box<dyn (FnOnce() -> Pin<Box<dyn Future<Output = AccessResult>>>) + Send + 'static>,
+    options: TaskOptions,
+    location: &'static Location<'static>,
+}
 
 impl SpawnFn {
+    #[track_caller]
     fn new<F: Future<Output = AccessResult> + 'static>(
+        options: TaskOptions,
         f: impl (FnOnce() -> F) + Send + 'static,
     ) -> Self {
-        Self(Box::new(move || Box::pin(f())))
+        Self {
+            future: Box::new(move || Box::pin(f())),
+            options,
+            location: Location::caller(),
+        }
     }
 }
 
@@ -536,7 +741,15 @@ impl Command for SpawnFn {
     type Out = ();
 
     fn apply(self, world: &mut World) {
-        world.spawn_task(self.0());
+        let future = (self.future)();
+        world
+            .non_send::<AsyncExecutor>()
+            .spawn_located(self.options, self.location, async {
+                if let Err(e) = future.await {
+                    error!("{e}")
+                }
+            })
+            .detach();
     }
 }
 
@@ -544,9 +757,11 @@ impl Command for SpawnFn {
 pub struct StateScopedSpawnFn<S: States> {
     future: Box<dyn (FnOnce() -> Pin<Box<dyn Future<Output = AccessResult>>>) + Send + 'static>,
     state: S,
+    location: &'static Location<'static>,
 }
 
 impl<S: States> StateScopedSpawnFn<S> {
+    #[track_caller]
     fn new<F: Future<Output = AccessResult> + 'static>(
         state: S,
         f: impl (FnOnce() -> F) + Send + 'static,
@@ -554,6 +769,7 @@ impl<S: States> StateScopedSpawnFn<S> {
         Self {
             future: Box::new(move || Box::pin(f())),
             state,
+            location: Location::caller(),
         }
     }
 }
@@ -562,7 +778,43 @@ impl<S: States> Command for StateScopedSpawnFn<S> {
     type Out = ();
 
     fn apply(self, world: &mut World) {
-        let _ = world.spawn_state_scoped(self.state, (self.future)());
+        let _ = spawn_state_scoped_at(world, self.state, (self.future)(), self.location);
+    }
+}
+
+/// [`Command`] for spawning a task.
+pub struct EntityScopedSpawnFn {
+    future: Box<dyn (FnOnce() -> Pin<Box<dyn Future<Output = AccessResult>>>) + Send + 'static>,
+    entity: Entity,
+    location: &'static Location<'static>,
+}
+
+impl EntityScopedSpawnFn {
+    #[track_caller]
+    fn new<F: Future<Output = AccessResult> + 'static>(
+        entity: Entity,
+        f: impl (FnOnce() -> F) + Send + 'static,
+    ) -> Self {
+        Self {
+            future: Box::new(move || Box::pin(f())),
+            entity,
+            location: Location::caller(),
+        }
+    }
+}
+
+impl Command for EntityScopedSpawnFn {
+    type Out = ();
+
+    fn apply(self, world: &mut World) {
+        let executor = world.non_send::<AsyncExecutor>().clone();
+        let _ = spawn::spawn_entity_scoped_at(
+            world,
+            &executor,
+            self.entity,
+            (self.future)(),
+            self.location,
+        );
     }
 }
 
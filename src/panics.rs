@@ -0,0 +1,69 @@
+//! Reporting of panics in spawned tasks.
+use bevy::ecs::message::Message;
+use bevy::ecs::resource::Resource;
+use std::any::Any;
+use std::borrow::Cow;
+use std::panic::Location;
+use std::sync::Arc;
+
+/// [`Message`] sent when a task on the [`AsyncExecutor`](crate::AsyncExecutor) panics.
+///
+/// The panic is still propagated to the task's handle,
+/// i.e. awaiting the [`Task`](crate::Task) will panic.
+#[derive(Debug, Clone, Message)]
+pub struct TaskPanicked {
+    /// Id of the task in the [`TaskRegistry`](crate::TaskRegistry).
+    pub id: u64,
+    /// Name of the task, if given.
+    pub name: Option<Cow<'static, str>>,
+    /// Where the task is spawned.
+    pub location: &'static Location<'static>,
+    /// The panic payload, if it is a string.
+    pub message: Option<String>,
+}
+
+impl TaskPanicked {
+    pub(crate) fn new(
+        id: u64,
+        name: Option<Cow<'static, str>>,
+        location: &'static Location<'static>,
+        payload: &(dyn Any + Send),
+    ) -> Self {
+        let message = if let Some(s) = payload.downcast_ref::<&str>() {
+            Some(s.to_string())
+        } else {
+            payload.downcast_ref::<String>().cloned()
+        };
+        TaskPanicked {
+            id,
+            name,
+            location,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for TaskPanicked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "Task {} \"{}\"", self.id, name)?,
+            None => write!(f, "Task {}", self.id)?,
+        }
+        write!(f, " spawned at {} panicked", self.location)?;
+        match &self.message {
+            Some(message) => write!(f, ": {message}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Resource containing a function called when a task panics,
+/// added via [`AsyncPlugin::on_panic`](crate::AsyncPlugin::on_panic).
+#[derive(Clone, Resource)]
+pub struct TaskPanicHook(pub Arc<dyn Fn(&TaskPanicked) + Send + Sync>);
+
+impl std::fmt::Debug for TaskPanicHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TaskPanicHook").finish_non_exhaustive()
+    }
+}
@@ -1,15 +1,25 @@
-use crate::executor::QUERY_QUEUE;
+use crate::executor::{
+    run_async_executor_with, ExecutorBudget, CURRENT_TASK, CURRENT_TASK_ID, QUERY_QUEUE,
+};
 use crate::sync::oneshot::ChannelOutOrCancel;
 use crate::sync::waitlist::WaitList;
-use crate::AccessResult;
 use crate::{access::AsyncWorld, cancellation::TaskCancellation, channel, sync::oneshot::Sender};
+use crate::{AccessError, AccessResult};
+use bevy::app::FixedUpdate;
 use bevy::diagnostic::FrameCount;
+use bevy::ecs::intern::Interned;
+use bevy::ecs::schedule::{IntoScheduleConfigs, ScheduleLabel, Schedules};
 use bevy::ecs::system::NonSend;
 use bevy::ecs::system::Res;
 use bevy::ecs::world::World;
 use bevy::time::{Time, Virtual};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::future::{poll_fn, Future};
 use std::ops::Deref;
 use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
 use std::time::Duration;
 use std::{cell::Cell, cell::RefCell, collections::BinaryHeap};
 
@@ -20,6 +30,8 @@ use bevy::app::Update;
 pub(crate) struct FixedTask {
     task: Box<dyn FnMut(&mut World, Duration) -> bool>,
     cancel: TaskCancellation,
+    /// Tags of the spawning task, the task does not run while any of them is paused.
+    tags: Vec<Cow<'static, str>>,
 }
 
 /// A deferred query on a `World`.
@@ -62,6 +74,49 @@ impl Deref for QueryQueue {
     }
 }
 
+/// Clock and timers of tasks with the same tags,
+/// which only advance while none of the tags is paused.
+#[derive(Default)]
+pub(crate) struct TaskGroup {
+    now: Duration,
+    frame: u32,
+    time_series: BinaryHeap<TimeIndex<Duration, Sender<()>>>,
+    frame_series: BinaryHeap<TimeIndex<u32, Sender<()>>>,
+}
+
+impl TaskGroup {
+    fn is_empty(&self) -> bool {
+        self.time_series.is_empty() && self.frame_series.is_empty()
+    }
+
+    fn advance(&mut self, dt: Duration, frames: u32) {
+        self.now += dt;
+        self.frame = self.frame.wrapping_add(frames);
+        while self
+            .time_series
+            .peek()
+            .map(|x| x.0 <= self.now)
+            .unwrap_or(false)
+        {
+            let _ = self.time_series.pop().unwrap().1.send(());
+        }
+        while self
+            .frame_series
+            .peek()
+            .map(|x| x.0 <= self.frame)
+            .unwrap_or(false)
+        {
+            let _ = self.frame_series.pop().unwrap().1.send(());
+        }
+    }
+}
+
+/// Timers of a [`Time<T>`] clock other than [`Time<Virtual>`].
+pub(crate) struct Clock {
+    elapsed: fn(&World) -> Option<Duration>,
+    time_series: BinaryHeap<TimeIndex<Duration, Sender<()>>>,
+}
+
 /// Queue for deferred `!Send` queries applied on the [`World`].
 #[derive(Default)]
 pub struct QueryQueueInner {
@@ -73,6 +128,28 @@ pub struct QueryQueueInner {
     pub(crate) now: Cell<Duration>,
     pub(crate) dt: Cell<Duration>,
     pub(crate) frame: Cell<u32>,
+    /// Timers of tagged tasks, keyed by the tags of the task.
+    pub(crate) groups: RefCell<FxHashMap<Vec<Cow<'static, str>>, TaskGroup>>,
+    /// Paused tags and the wakers of tasks waiting for them to resume.
+    pub(crate) paused: RefCell<FxHashMap<Cow<'static, str>, Vec<Waker>>>,
+    pub(crate) clocks: RefCell<FxHashMap<TypeId, Clock>>,
+    pub(crate) schedules: RefCell<FxHashMap<Interned<dyn ScheduleLabel>, ScheduleHook>>,
+    /// While a schedule hook runs the executor, ids of the tasks resumed by the schedule.
+    pub(crate) schedule_gate: RefCell<Option<FxHashSet<u64>>>,
+    /// Tasks deferred by `schedule_gate` until the hook completes.
+    pub(crate) gated: WaitList,
+}
+
+/// Futures waiting on a schedule with a hook added by [`AsyncPlugin::with_schedule_hook`]
+/// or [`AsyncWorld::next_fixed_tick`].
+///
+/// [`AsyncPlugin::with_schedule_hook`]: crate::AsyncPlugin::with_schedule_hook
+#[derive(Debug, Default)]
+pub(crate) struct ScheduleHook {
+    runs: u64,
+    waiting: Vec<Waker>,
+    /// Ids of the tasks waiting, only these are polled by the hook.
+    tasks: FxHashSet<u64>,
 }
 
 impl std::fmt::Debug for QueryQueue {
@@ -86,6 +163,11 @@ impl std::fmt::Debug for QueryQueue {
             .field("now", &self.now.get())
             .field("now", &self.dt.get())
             .field("frame", &self.frame.get())
+            .field("groups", &self.groups.borrow().len())
+            .field("paused", &self.paused.borrow().len())
+            .field("clocks", &self.clocks.borrow().len())
+            .field("schedules", &self.schedules.borrow().len())
+            .field("gated", &self.gated.len())
             .finish_non_exhaustive()
     }
 }
@@ -128,20 +210,114 @@ impl QueryQueue {
     }
 
     /// Notify after a certain time.
+    ///
+    /// If called in a task with tags, uses a clock shared by tasks with the same tags,
+    /// which does not advance while any of the tags is paused.
     pub fn timed(&self, duration: Duration, channel: Sender<()>) {
+        if let Some(tags) = current_tags() {
+            let mut groups = self.groups.borrow_mut();
+            let group = groups.entry(tags).or_default();
+            group
+                .time_series
+                .push(TimeIndex(group.now + duration, channel));
+            return;
+        }
         self.time_series
             .borrow_mut()
             .push(TimeIndex(self.now.get() + duration, channel))
     }
 
+    /// Notify when [`AsyncWorld::now`] reaches `at`.
+    ///
+    /// Unlike [`QueryQueue::timed`], does not use the clock of the task's tags.
+    pub fn timed_at(&self, at: Duration, channel: Sender<()>) {
+        self.time_series.borrow_mut().push(TimeIndex(at, channel))
+    }
+
+    /// Notify when [`AsyncWorld::frame_count`] reaches `frame`.
+    ///
+    /// Unlike [`QueryQueue::timed_frames`], does not use the frame count of the task's tags.
+    pub fn timed_at_frame(&self, frame: u32, channel: Sender<()>) {
+        self.frame_series
+            .borrow_mut()
+            .push(TimeIndex(frame, channel))
+    }
+
+    /// Notify after a certain time according to [`Time<T>`].
+    ///
+    /// Does not use the clock of the task's tags.
+    pub fn timed_on<T: Default + Send + Sync + 'static>(
+        &self,
+        world: &World,
+        duration: Duration,
+        channel: Sender<()>,
+    ) {
+        fn elapsed<T: Default + Send + Sync + 'static>(world: &World) -> Option<Duration> {
+            world.get_resource::<Time<T>>().map(|t| t.elapsed())
+        }
+        let now = elapsed::<T>(world).unwrap_or_default();
+        self.clocks
+            .borrow_mut()
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Clock {
+                elapsed: elapsed::<T>,
+                time_series: BinaryHeap::new(),
+            })
+            .time_series
+            .push(TimeIndex(now + duration, channel))
+    }
+
     /// Notify after a certain frame.
+    ///
+    /// If called in a task with tags, uses a frame count shared by tasks with the same tags,
+    /// which does not advance while any of the tags is paused.
     pub fn timed_frames(&self, duration: u32, channel: Sender<()>) {
+        if let Some(tags) = current_tags() {
+            let mut groups = self.groups.borrow_mut();
+            let group = groups.entry(tags).or_default();
+            group
+                .frame_series
+                .push(TimeIndex(group.frame.wrapping_add(duration), channel));
+            return;
+        }
         self.frame_series
             .borrow_mut()
             .push(TimeIndex(self.frame.get() + duration, channel))
     }
 
+    /// Stop polling tasks with a tag and freeze their timers.
+    pub fn pause_tag(&self, tag: impl Into<Cow<'static, str>>) {
+        self.paused.borrow_mut().entry(tag.into()).or_default();
+    }
+
+    /// Resume tasks paused by [`QueryQueue::pause_tag`].
+    pub fn resume_tag(&self, tag: &str) {
+        let resume = self.paused.borrow_mut().remove(tag);
+        resume.into_iter().flatten().for_each(|w| w.wake());
+    }
+
+    /// Returns `true` if a tag is paused.
+    pub fn is_tag_paused(&self, tag: &str) -> bool {
+        self.paused.borrow().contains_key(tag)
+    }
+
+    /// If any of the tags is paused, register the waker for resume and returns `true`.
+    pub(crate) fn poll_paused(&self, tags: &[Cow<'static, str>], cx: &Context) -> bool {
+        let mut paused = self.paused.borrow_mut();
+        for tag in tags {
+            if let Some(resume) = paused.get_mut(tag.as_ref()) {
+                if !resume.iter().any(|w| w.will_wake(cx.waker())) {
+                    resume.push(cx.waker().clone());
+                }
+                return true;
+            }
+        }
+        false
+    }
+
     /// Run a repeatable routine on [`Update`], with access to delta time.
+    ///
+    /// If called in a task with tags, the routine does not run while any of the tags is paused.
     pub fn timed_routine<T: 'static>(
         &self,
         mut f: impl FnMut(&mut World, Duration) -> Option<T> + 'static,
@@ -160,6 +336,7 @@ impl QueryQueue {
                 }
             }),
             cancel,
+            tags: current_tags().unwrap_or_default(),
         });
         receiver.into_option()
     }
@@ -180,12 +357,17 @@ pub fn run_watch_queries(world: &mut World) {
 pub fn run_fixed_queue(world: &mut World) {
     let query_queue = world.remove_non_send::<QueryQueue>().unwrap();
     let delta_time = world.resource::<Time>().delta();
+    let paused = query_queue.paused.borrow();
     query_queue.fixed_queue.borrow_mut().retain_mut(|x| {
         if x.cancel.cancelled() {
             return false;
         }
+        if x.tags.iter().any(|tag| paused.contains_key(tag)) {
+            return true;
+        }
         !(x.task)(world, delta_time)
     });
+    drop(paused);
     world.insert_non_send(query_queue);
 }
 
@@ -196,8 +378,16 @@ pub fn run_time_series(
     frames: Res<FrameCount>,
 ) {
     let now = time.elapsed();
+    let frames_elapsed = frames.0.wrapping_sub(queue.frame.get());
     queue.now.set(now);
     queue.dt.set(time.delta());
+    let paused = queue.paused.borrow();
+    queue.groups.borrow_mut().retain(|tags, group| {
+        if !tags.iter().any(|tag| paused.contains_key(tag)) {
+            group.advance(time.delta(), frames_elapsed);
+        }
+        !group.is_empty()
+    });
     let mut time_series = queue.time_series.borrow_mut();
     while time_series.peek().map(|x| x.0 <= now).unwrap_or(false) {
         let _ = time_series.pop().unwrap().1.send(());
@@ -213,8 +403,230 @@ pub fn run_time_series(
     }
 }
 
+impl QueryQueueInner {
+    /// Returns `true` if there are `sleep_frames` timers that can fire.
+    pub(crate) fn has_frame_timers(&self) -> bool {
+        let paused = self.paused.borrow();
+        !self.frame_series.borrow().is_empty()
+            || self.groups.borrow().iter().any(|(tags, group)| {
+                !group.frame_series.is_empty() && !tags.iter().any(|tag| paused.contains_key(tag))
+            })
+    }
+
+    /// Returns the number of pending `sleep` and `sleep_frames` timers.
+    pub(crate) fn pending_timers(&self) -> usize {
+        self.time_series.borrow().len()
+            + self.frame_series.borrow().len()
+            + self
+                .groups
+                .borrow()
+                .values()
+                .map(|g| g.time_series.len() + g.frame_series.len())
+                .sum::<usize>()
+            + self
+                .clocks
+                .borrow()
+                .values()
+                .map(|c| c.time_series.len())
+                .sum::<usize>()
+    }
+}
+
+impl QueryQueueInner {
+    /// Wakes futures waiting on a schedule, returns the ids of their tasks if any.
+    fn wake_schedule(&self, schedule: Interned<dyn ScheduleLabel>) -> Option<FxHashSet<u64>> {
+        let (waiting, tasks) = {
+            let mut schedules = self.schedules.borrow_mut();
+            let hook = schedules.entry(schedule).or_default();
+            hook.runs += 1;
+            (
+                std::mem::take(&mut hook.waiting),
+                std::mem::take(&mut hook.tasks),
+            )
+        };
+        waiting.into_iter().for_each(|w| w.wake());
+        (!tasks.is_empty()).then_some(tasks)
+    }
+
+    /// While a schedule hook runs, defer tasks not resumed by the schedule
+    /// until the hook completes and returns `true`.
+    pub(crate) fn poll_gated(&self, task: u64, cx: &Context) -> bool {
+        match self.schedule_gate.borrow().as_ref() {
+            Some(tasks) if !tasks.contains(&task) => {
+                self.gated.push_cx(cx);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Create a system that resumes futures waiting on [`AsyncWorld::next_schedule`]
+/// and runs the executor if any.
+///
+/// Only tasks waiting on the schedule are polled, other tasks are deferred
+/// to the next run of [`run_async_executor`](crate::systems::run_async_executor).
+pub(crate) fn schedule_hook(schedule: Interned<dyn ScheduleLabel>) -> impl FnMut(&mut World) {
+    move |world: &mut World| {
+        let queue = world.non_send::<QueryQueue>().clone();
+        let Some(tasks) = queue.wake_schedule(schedule) else {
+            return;
+        };
+        *queue.schedule_gate.borrow_mut() = Some(tasks);
+        run_async_executor_with(world, ExecutorBudget::Unlimited);
+        *queue.schedule_gate.borrow_mut() = None;
+        queue.gated.wake();
+    }
+}
+
+/// Add a hook to a schedule for [`AsyncWorld::next_schedule`], does nothing if already added.
+pub(crate) fn add_schedule_hook(world: &mut World, schedule: Interned<dyn ScheduleLabel>) {
+    if world
+        .non_send::<QueryQueue>()
+        .schedules
+        .borrow()
+        .contains_key(&schedule)
+    {
+        return;
+    }
+    world
+        .non_send::<QueryQueue>()
+        .schedules
+        .borrow_mut()
+        .insert(schedule, ScheduleHook::default());
+    world.resource_mut::<Schedules>().add_systems(
+        schedule,
+        schedule_hook(schedule).after(crate::systems::run_async_executor),
+    );
+}
+
+/// Run `sleep_on` reactors, e.g. `sleep_real` and `sleep_fixed`.
+pub fn run_clocks(queue: NonSend<QueryQueue>, world: &World) {
+    for clock in queue.clocks.borrow_mut().values_mut() {
+        let Some(now) = (clock.elapsed)(world) else {
+            continue;
+        };
+        while clock
+            .time_series
+            .peek()
+            .map(|x| x.0 <= now)
+            .unwrap_or(false)
+        {
+            let _ = clock.time_series.pop().unwrap().1.send(());
+        }
+    }
+}
+
+/// Returns the tags of the current task, if any.
+fn current_tags() -> Option<Vec<Cow<'static, str>>> {
+    if !CURRENT_TASK.is_set() {
+        return None;
+    }
+    CURRENT_TASK.with(|task| (!task.tags.is_empty()).then(|| task.tags.clone()))
+}
+
+impl AsyncWorld {
+    /// Wait until the next run of a schedule, the future resumes in that schedule
+    /// right after the [`AsyncExecutor`](crate::AsyncExecutor) would have run.
+    ///
+    /// Requires a hook added via [`AsyncPlugin::with_schedule_hook`],
+    /// [`FixedUpdate`] always has one.
+    ///
+    /// # Errors
+    ///
+    /// If the schedule does not have a hook.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// AsyncWorld.next_fixed_tick().await;
+    /// AsyncWorld.next_schedule(FixedUpdate).await?;
+    /// # });
+    /// ```
+    ///
+    /// [`AsyncPlugin::with_schedule_hook`]: crate::AsyncPlugin::with_schedule_hook
+    pub fn next_schedule(
+        &self,
+        schedule: impl ScheduleLabel,
+    ) -> impl Future<Output = AccessResult> + 'static {
+        let schedule = schedule.intern();
+        // Recorded on first poll, so a future polled late still waits for the next run.
+        let mut runs = None;
+        poll_fn(move |cx| {
+            QUERY_QUEUE.with(|queue| {
+                let mut schedules = queue.schedules.borrow_mut();
+                let Some(hook) = schedules.get_mut(&schedule) else {
+                    return Poll::Ready(Err(AccessError::ScheduleNotFound));
+                };
+                if hook.runs > *runs.get_or_insert(hook.runs) {
+                    return Poll::Ready(Ok(()));
+                }
+                if !hook.waiting.iter().any(|w| w.will_wake(cx.waker())) {
+                    hook.waiting.push(cx.waker().clone());
+                }
+                if CURRENT_TASK_ID.is_set() {
+                    CURRENT_TASK_ID.with(|id| hook.tasks.insert(*id));
+                }
+                Poll::Pending
+            })
+        })
+    }
+
+    /// Wait until the next [`FixedUpdate`](bevy::app::FixedUpdate) step,
+    /// the future resumes inside [`FixedUpdate`](bevy::app::FixedUpdate).
+    ///
+    /// Resumes exactly once per step, even if multiple steps run in a frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// for _ in 0..4 {
+    ///     AsyncWorld.next_fixed_tick().await;
+    /// }
+    /// # });
+    /// ```
+    pub async fn next_fixed_tick(&self) {
+        let _ = self.next_schedule(FixedUpdate).await;
+    }
+
+    /// Stop polling tasks with a tag and freeze their timers,
+    /// e.g. `sleep` and `sleep_frames` in these tasks.
+    ///
+    /// Tasks paused this way are not dropped and will continue on [`AsyncWorld::resume_tag`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// AsyncWorld.spawn_any_with(TaskOptions::new().with_tag("gameplay"), async {
+    ///     AsyncWorld.sleep(1.0).await;
+    /// });
+    /// AsyncWorld.pause_tag("gameplay");
+    /// assert!(AsyncWorld.is_tag_paused("gameplay"));
+    /// AsyncWorld.resume_tag("gameplay");
+    /// # });
+    /// ```
+    pub fn pause_tag(&self, tag: impl Into<Cow<'static, str>>) {
+        QUERY_QUEUE.with(|queue| queue.pause_tag(tag))
+    }
+
+    /// Resume tasks paused by [`AsyncWorld::pause_tag`].
+    pub fn resume_tag(&self, tag: &str) {
+        QUERY_QUEUE.with(|queue| queue.resume_tag(tag))
+    }
+
+    /// Returns `true` if a tag is paused.
+    pub fn is_tag_paused(&self, tag: &str) -> bool {
+        QUERY_QUEUE.with(|queue| queue.is_tag_paused(tag))
+    }
+}
+
 impl AsyncWorld {
     /// Run a repeatable routine on [`Update`], with access to delta time.
+    ///
+    /// If called in a task with tags, the routine does not run while any of the tags is paused.
     pub fn timed_routine<T: 'static>(
         &self,
         f: impl FnMut(&mut World, Duration) -> Option<T> + 'static,
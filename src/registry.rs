@@ -0,0 +1,307 @@
+//! Names, tags and live statistics of tasks running on the [`AsyncExecutor`](crate::AsyncExecutor).
+use bevy::ecs::resource::Resource;
+use bevy::platform::time::Instant;
+use rustc_hash::FxHashMap;
+use std::any::Any;
+use std::borrow::Cow;
+use std::panic::Location;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::executor::QUERY_QUEUE;
+use crate::panics::TaskPanicked;
+
+/// Options for spawning a task, like its name and tags.
+///
+/// `&'static str` and `String` can be converted into a named [`TaskOptions`].
+///
+/// # Example
+///
+/// ```
+/// # bevy_defer::test_spawn!({
+/// AsyncWorld.spawn_task_with(
+///     TaskOptions::named("enemy_ai").with_tag("enemy"),
+///     async { AsyncWorld.sleep(1.0).await; },
+/// ).detach();
+/// AsyncWorld.spawn_any_with("cleanup", async {});
+/// # });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskOptions {
+    pub(crate) name: Option<Cow<'static, str>>,
+    pub(crate) tags: Vec<Cow<'static, str>>,
+    pub(crate) priority: TaskPriority,
+    pub(crate) inherit_locals: bool,
+}
+
+/// Priority of a task on the [`AsyncExecutor`](crate::AsyncExecutor).
+///
+/// Tasks with a higher priority are always polled first in a run of the executor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TaskPriority {
+    /// Polled before all other tasks, not limited by [`ExecutorBudget`](crate::ExecutorBudget).
+    ///
+    /// Use for short tasks that must respond immediately, like input handling.
+    High,
+    /// The default priority.
+    #[default]
+    Normal,
+    /// Polled only if no other task can make progress.
+    ///
+    /// Use for bulk work like streaming or level generation.
+    Background,
+}
+
+impl TaskOptions {
+    /// Create an anonymous [`TaskOptions`].
+    pub fn new() -> Self {
+        TaskOptions::default()
+    }
+
+    /// Create a [`TaskOptions`] with a name.
+    pub fn named(name: impl Into<Cow<'static, str>>) -> Self {
+        TaskOptions {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the name of the task.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Add a tag to the task.
+    pub fn with_tag(mut self, tag: impl Into<Cow<'static, str>>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Set the priority of the task.
+    pub fn with_priority(mut self, priority: TaskPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Start the task with a copy of the [`TaskLocalKey`](crate::TaskLocalKey) values
+    /// of the spawning task.
+    ///
+    /// Has no effect if not spawned from a task.
+    pub fn inherit_locals(mut self) -> Self {
+        self.inherit_locals = true;
+        self
+    }
+}
+
+impl From<TaskPriority> for TaskOptions {
+    fn from(value: TaskPriority) -> Self {
+        TaskOptions::new().with_priority(value)
+    }
+}
+
+impl From<&'static str> for TaskOptions {
+    fn from(value: &'static str) -> Self {
+        TaskOptions::named(value)
+    }
+}
+
+impl From<String> for TaskOptions {
+    fn from(value: String) -> Self {
+        TaskOptions::named(value)
+    }
+}
+
+#[derive(Debug)]
+struct TaskEntry {
+    id: u64,
+    options: TaskOptions,
+    location: &'static Location<'static>,
+    spawned: Instant,
+    polls: AtomicU64,
+    last_polled_frame: AtomicU32,
+}
+
+impl TaskEntry {
+    fn info(&self) -> TaskInfo {
+        let polls = self.polls.load(Ordering::Acquire);
+        TaskInfo {
+            id: self.id,
+            name: self.options.name.clone(),
+            tags: self.options.tags.clone(),
+            priority: self.options.priority,
+            location: self.location,
+            spawned: self.spawned,
+            polls,
+            last_polled_frame: (polls > 0).then(|| self.last_polled_frame.load(Ordering::Acquire)),
+        }
+    }
+}
+
+/// Snapshot of a live task in the [`TaskRegistry`].
+#[derive(Debug, Clone)]
+pub struct TaskInfo {
+    /// Unique id of the task, assigned in spawn order.
+    pub id: u64,
+    /// Name of the task, if given.
+    pub name: Option<Cow<'static, str>>,
+    /// Tags of the task.
+    pub tags: Vec<Cow<'static, str>>,
+    /// Priority of the task.
+    pub priority: TaskPriority,
+    /// Where the task is spawned.
+    pub location: &'static Location<'static>,
+    /// When the task is spawned.
+    pub spawned: Instant,
+    /// Number of times the task has been polled.
+    pub polls: u64,
+    /// Value of `FrameCount` when the task is last polled, `None` if never polled.
+    pub last_polled_frame: Option<u32>,
+}
+
+impl TaskInfo {
+    /// Time elapsed since the task is spawned.
+    pub fn age(&self) -> Duration {
+        self.spawned.elapsed()
+    }
+
+    /// Returns `true` if the task has a tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+impl std::fmt::Display for TaskInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "Task {} \"{}\"", self.id, name)?,
+            None => write!(f, "Task {}", self.id)?,
+        }
+        write!(
+            f,
+            " at {}, age {:.2?}, polled {} times",
+            self.location,
+            self.age(),
+            self.polls
+        )?;
+        if let Some(frame) = self.last_polled_frame {
+            write!(f, ", last polled on frame {frame}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+struct TaskRegistryInner {
+    next_id: AtomicU64,
+    tasks: Mutex<FxHashMap<u64, Arc<TaskEntry>>>,
+}
+
+/// Resource listing live tasks on the [`AsyncExecutor`](crate::AsyncExecutor).
+///
+/// Tasks are added when spawned and removed when completed or dropped.
+#[derive(Debug, Resource, Default, Clone)]
+pub struct TaskRegistry(Arc<TaskRegistryInner>);
+
+impl TaskRegistry {
+    /// Returns the number of live tasks.
+    pub fn len(&self) -> usize {
+        self.0.tasks.lock().unwrap().len()
+    }
+
+    /// Returns `true` if there are no live tasks.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Obtain a snapshot of all live tasks, ordered by spawn order.
+    pub fn tasks(&self) -> Vec<TaskInfo> {
+        self.filter(|_| true)
+    }
+
+    /// Obtain a snapshot of all live tasks with a name, ordered by spawn order.
+    pub fn named(&self, name: &str) -> Vec<TaskInfo> {
+        self.filter(|entry| entry.options.name.as_deref() == Some(name))
+    }
+
+    /// Obtain a snapshot of all live tasks with a tag, ordered by spawn order.
+    pub fn tagged(&self, tag: &str) -> Vec<TaskInfo> {
+        self.filter(|entry| entry.options.tags.iter().any(|t| t == tag))
+    }
+
+    fn filter(&self, f: impl Fn(&TaskEntry) -> bool) -> Vec<TaskInfo> {
+        let mut result: Vec<_> = self
+            .0
+            .tasks
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|entry| f(entry))
+            .map(|entry| entry.info())
+            .collect();
+        result.sort_by_key(|x| x.id);
+        result
+    }
+
+    pub(crate) fn register(
+        &self,
+        options: TaskOptions,
+        location: &'static Location<'static>,
+    ) -> RegisteredTask {
+        let id = self.0.next_id.fetch_add(1, Ordering::AcqRel);
+        let entry = Arc::new(TaskEntry {
+            id,
+            options,
+            location,
+            spawned: Instant::now(),
+            polls: AtomicU64::new(0),
+            last_polled_frame: AtomicU32::new(0),
+        });
+        self.0.tasks.lock().unwrap().insert(id, entry.clone());
+        RegisteredTask {
+            registry: self.clone(),
+            entry,
+        }
+    }
+}
+
+/// Entry of a task in the [`TaskRegistry`], removes the entry on drop.
+#[derive(Debug)]
+pub(crate) struct RegisteredTask {
+    registry: TaskRegistry,
+    entry: Arc<TaskEntry>,
+}
+
+impl RegisteredTask {
+    pub(crate) fn id(&self) -> u64 {
+        self.entry.id
+    }
+
+    pub(crate) fn options(&self) -> &TaskOptions {
+        &self.entry.options
+    }
+
+    pub(crate) fn panicked(&self, payload: &(dyn Any + Send)) -> TaskPanicked {
+        TaskPanicked::new(
+            self.entry.id,
+            self.entry.options.name.clone(),
+            self.entry.location,
+            payload,
+        )
+    }
+
+    pub(crate) fn polled(&self) {
+        self.entry.polls.fetch_add(1, Ordering::AcqRel);
+        if QUERY_QUEUE.is_set() {
+            let frame = QUERY_QUEUE.with(|q| q.frame.get());
+            self.entry.last_polled_frame.store(frame, Ordering::Release);
+        }
+    }
+}
+
+impl Drop for RegisteredTask {
+    fn drop(&mut self) {
+        self.registry.0.tasks.lock().unwrap().remove(&self.entry.id);
+    }
+}
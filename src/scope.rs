@@ -0,0 +1,200 @@
+//! Structured concurrency for `bevy_defer`.
+use async_executor::LocalExecutor;
+use futures::FutureExt;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::future::{poll_fn, Future};
+use std::panic::{resume_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::task::{Context, Poll};
+
+use crate::sync::oneshot::{channel, ChannelOutOrCancel};
+use crate::sync::waitlist::WaitList;
+use crate::{AccessError, AccessResult, AsyncWorld};
+
+#[derive(Default)]
+struct ScopeState {
+    running: Cell<usize>,
+    error: Cell<Option<AccessError>>,
+    panic: RefCell<Option<Box<dyn Any + Send>>>,
+    wait: WaitList,
+}
+
+impl std::fmt::Debug for ScopeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScopeState")
+            .field("running", &self.running)
+            .field("error", &self.error)
+            .field("panicked", &self.panic.borrow().is_some())
+            .field("wait", &self.wait)
+            .finish()
+    }
+}
+
+impl ScopeState {
+    fn fail(&self, error: AccessError) {
+        if self.error.get().is_none() {
+            self.error.set(Some(error));
+        }
+        self.wait.wake();
+    }
+
+    fn panicked(&self, payload: Box<dyn Any + Send>) {
+        self.panic.borrow_mut().get_or_insert(payload);
+        self.wait.wake();
+    }
+
+    fn completed(&self) -> bool {
+        self.running.get() == 0 || self.error.get().is_some() || self.panic.borrow().is_some()
+    }
+}
+
+/// Decrements the number of running children on completion or cancellation.
+struct ChildGuard(Rc<ScopeState>);
+
+impl Drop for ChildGuard {
+    fn drop(&mut self) {
+        self.0.running.set(self.0.running.get() - 1);
+        if self.0.running.get() == 0 {
+            self.0.wait.wake();
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ScopeInner<'env> {
+    executor: LocalExecutor<'env>,
+    state: Rc<ScopeState>,
+}
+
+/// A handle for spawning child tasks in [`AsyncWorld::scope`].
+///
+/// Child tasks can borrow data that outlives the scope.
+#[derive(Debug, Clone)]
+pub struct Scope<'env>(Weak<ScopeInner<'env>>);
+
+impl<'env> Scope<'env> {
+    /// Spawn a child task in the scope.
+    ///
+    /// Returns the output of the child, or `None` if the child failed or was cancelled.
+    /// The output can be ignored, the scope always waits for the child to complete.
+    ///
+    /// If the child returns an error, all other children are cancelled
+    /// and the error is returned from the scope.
+    /// If the child panics, all other children are cancelled
+    /// and the panic is resumed in the task running the scope.
+    ///
+    /// # Panics
+    ///
+    /// If the scope has already completed.
+    pub fn spawn<T: 'env>(
+        &self,
+        fut: impl Future<Output = AccessResult<T>> + 'env,
+    ) -> ScopeOutput<T> {
+        let Some(inner) = self.0.upgrade() else {
+            panic!("Scope::spawn cannot be used after the scope has completed.")
+        };
+        let (sender, receiver) = channel();
+        let state = inner.state.clone();
+        state.running.set(state.running.get() + 1);
+        inner
+            .executor
+            .spawn(async move {
+                let guard = ChildGuard(state);
+                match AssertUnwindSafe(fut).catch_unwind().await {
+                    Ok(Ok(value)) => {
+                        let _ = sender.send(value);
+                    }
+                    Ok(Err(e)) => guard.0.fail(e),
+                    Err(payload) => guard.0.panicked(payload),
+                }
+            })
+            .detach();
+        ScopeOutput(receiver.into_option())
+    }
+}
+
+/// Output of a child task in a [`Scope`], or `None` if the child failed or was cancelled.
+///
+/// Unlike [`Task`](crate::Task), this can be dropped without cancelling the child.
+#[derive(Debug)]
+pub struct ScopeOutput<T>(ChannelOutOrCancel<T>);
+
+impl<T> Unpin for ScopeOutput<T> {}
+
+impl<T> Future for ScopeOutput<T> {
+    type Output = Option<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+impl AsyncWorld {
+    /// Run a future in a [`Scope`] that can spawn child tasks.
+    ///
+    /// Child tasks run on an executor owned by the scope, which is driven by the current task,
+    /// so children can borrow from the parent future.
+    ///
+    /// The scope does not complete until the body and all children have completed.
+    /// If any of them returns an error, the remaining children are cancelled and
+    /// the first error is returned.
+    ///
+    /// # Panics
+    ///
+    /// If the body or any child panics, the remaining children are cancelled
+    /// and the first panic is resumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let names = std::cell::RefCell::new(Vec::new());
+    /// let names_ref = &names;
+    /// AsyncWorld.scope(|scope| async move {
+    ///     for i in 0..4 {
+    ///         scope.spawn(async move {
+    ///             AsyncWorld.sleep_frames(i).await;
+    ///             names_ref.borrow_mut().push(i);
+    ///             Ok(())
+    ///         });
+    ///     }
+    ///     Ok(())
+    /// }).await?;
+    /// assert_eq!(names.into_inner(), vec![0, 1, 2, 3]);
+    /// # });
+    /// ```
+    pub async fn scope<'env, T: 'env, F: Future<Output = AccessResult<T>> + 'env>(
+        &self,
+        f: impl FnOnce(Scope<'env>) -> F,
+    ) -> AccessResult<T> {
+        let inner = Rc::new(ScopeInner {
+            executor: LocalExecutor::new(),
+            state: Rc::default(),
+        });
+        let state = inner.state.clone();
+        let scope = Scope(Rc::downgrade(&inner));
+        let output = scope.spawn(f(scope.clone()));
+        inner
+            .executor
+            .run(poll_fn(|cx| {
+                if state.completed() {
+                    Poll::Ready(())
+                } else {
+                    state.wait.push_cx(cx);
+                    Poll::Pending
+                }
+            }))
+            .await;
+        // Cancels all remaining children.
+        drop(inner);
+        if let Some(payload) = state.panic.take() {
+            resume_unwind(payload);
+        }
+        if let Some(error) = state.error.take() {
+            return Err(error);
+        }
+        output.await.ok_or(AccessError::ShouldNotHappen)
+    }
+}
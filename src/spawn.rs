@@ -1,13 +1,19 @@
 use async_executor::Task;
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
 use bevy::ecs::prelude::Resource;
+use bevy::ecs::world::World;
 use bevy::log::error;
 use bevy::state::prelude::{State, States};
 use rustc_hash::FxHashMap;
 use std::any::type_name;
+use std::panic::Location;
 use std::{future::Future, marker::PhantomData};
 
+use crate::access::{AsyncEntity, VirtualEntity};
 use crate::executor::{with_world_mut, with_world_ref};
-use crate::{executor::SPAWNER, AccessError, AccessResult, AsyncWorld};
+use crate::registry::TaskOptions;
+use crate::{executor::SPAWNER, AccessError, AccessResult, AsyncExecutor, AsyncWorld};
 
 /// A list of tasks constrained by [`States`].
 #[derive(Debug, Resource)]
@@ -38,6 +44,69 @@ impl<T: States> ScopedTasks<T> {
     }
 }
 
+/// A list of tasks constrained by an entity.
+///
+/// The tasks are dropped when the entity is despawned or this component is removed.
+#[derive(Debug, Default, Component)]
+pub struct EntityScopedTasks {
+    pub(crate) tasks: Vec<Task<AccessResult<()>>>,
+}
+
+impl EntityScopedTasks {
+    /// Create an empty list of tasks.
+    pub fn new() -> Self {
+        EntityScopedTasks::default()
+    }
+
+    /// Returns the number of unfinished tasks.
+    pub fn len(&self) -> usize {
+        self.tasks.iter().filter(|t| !t.is_finished()).count()
+    }
+
+    /// Returns `true` if all tasks are finished.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop tasks bound to the entity.
+    pub fn clear(&mut self) {
+        self.tasks.clear();
+    }
+
+    pub(crate) fn push(&mut self, task: Task<AccessResult<()>>) {
+        self.tasks.retain(|t| !t.is_finished());
+        self.tasks.push(task);
+    }
+}
+
+/// Spawn a task and store it in [`EntityScopedTasks`] on the entity.
+///
+/// Errors returned by the task are logged.
+pub(crate) fn spawn_entity_scoped_at(
+    world: &mut World,
+    executor: &AsyncExecutor,
+    entity: Entity,
+    fut: impl Future<Output = AccessResult> + 'static,
+    location: &'static Location<'static>,
+) -> AccessResult {
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+        return Err(AccessError::EntityNotFound(entity));
+    };
+    let task = executor.spawn_located(TaskOptions::default(), location, async move {
+        let result = fut.await;
+        if let Err(e) = &result {
+            error!("{e}");
+        }
+        result
+    });
+    if let Some(mut tasks) = entity_mut.get_mut::<EntityScopedTasks>() {
+        tasks.push(task);
+    } else {
+        entity_mut.insert(EntityScopedTasks { tasks: vec![task] });
+    }
+    Ok(())
+}
+
 impl AsyncWorld {
     /// Spawn a `bevy_defer` compatible future.
     ///
@@ -49,13 +118,30 @@ impl AsyncWorld {
     ///
     /// # Panic Handling
     ///
-    /// Due to the internals of `AsyncExecutor` this function will fail silently on panic.
-    /// Use `spawn_log` or `panic=abort` for better panic handling.
+    /// Panics are logged and reported as [`TaskPanicked`](crate::TaskPanicked) messages.
+    #[track_caller]
     pub fn spawn_any<T: 'static>(&self, fut: impl Future<Output = T> + 'static) {
+        self.spawn_any_with(TaskOptions::default(), fut)
+    }
+
+    /// Spawn a `bevy_defer` compatible future with [`TaskOptions`].
+    ///
+    /// The spawned future will not be dropped until finished.
+    ///
+    /// # Panics
+    ///
+    /// If used outside a `bevy_defer` future.
+    #[track_caller]
+    pub fn spawn_any_with<T: 'static>(
+        &self,
+        options: impl Into<TaskOptions>,
+        fut: impl Future<Output = T> + 'static,
+    ) {
         if !SPAWNER.is_set() {
             panic!("AsyncWorld::spawn_any can only be used in a bevy_defer future.")
         }
-        SPAWNER.with(|s| s.spawn(fut).detach());
+        let location = Location::caller();
+        SPAWNER.with(|s| s.spawn_located(options.into(), location, fut).detach());
     }
 
     /// Spawn a `bevy_defer` compatible future with a handle.
@@ -69,11 +155,33 @@ impl AsyncWorld {
     ///
     /// * If used outside a `bevy_defer` future.
     /// * If the task has panicked.
+    #[track_caller]
     pub fn spawn_task<T: 'static>(&self, fut: impl Future<Output = T> + 'static) -> Task<T> {
+        self.spawn_task_with(TaskOptions::default(), fut)
+    }
+
+    /// Spawn a `bevy_defer` compatible future with [`TaskOptions`] and a handle.
+    ///
+    /// # Handle
+    ///
+    /// The handle can be used to obtain the result,
+    /// if dropped, the associated future will be dropped by the executor.
+    ///
+    /// # Panics
+    ///
+    /// * If used outside a `bevy_defer` future.
+    /// * If the task has panicked.
+    #[track_caller]
+    pub fn spawn_task_with<T: 'static>(
+        &self,
+        options: impl Into<TaskOptions>,
+        fut: impl Future<Output = T> + 'static,
+    ) -> Task<T> {
         if !SPAWNER.is_set() {
-            panic!("AsyncWorld::spawn_scoped can only be used in a bevy_defer future.")
+            panic!("AsyncWorld::spawn_task can only be used in a bevy_defer future.")
         }
-        SPAWNER.with(|s| s.spawn(fut))
+        let location = Location::caller();
+        SPAWNER.with(|s| s.spawn_located(options.into(), location, fut))
     }
 
     /// Spawn a `bevy_defer` compatible future with a handle.
@@ -88,6 +196,7 @@ impl AsyncWorld {
     /// * If used outside a `bevy_defer` future.
     /// * If the task has panicked.
     #[deprecated = "Use `spawn_task`."]
+    #[track_caller]
     pub fn spawn_scoped<T: 'static>(
         &self,
         fut: impl Future<Output = T> + 'static,
@@ -95,7 +204,8 @@ impl AsyncWorld {
         if !SPAWNER.is_set() {
             panic!("AsyncWorld::spawn_scoped can only be used in a bevy_defer future.")
         }
-        SPAWNER.with(|s| s.spawn(fut))
+        let location = Location::caller();
+        SPAWNER.with(|s| s.spawn_located(TaskOptions::default(), location, fut))
     }
 
     /// Spawn a `bevy_defer` compatible future, the future is constrained to a [`States`]
@@ -108,6 +218,7 @@ impl AsyncWorld {
     /// # Panics
     ///
     /// * If used outside a `bevy_defer` future.
+    #[track_caller]
     pub fn spawn_state_scoped<S: States>(
         &self,
         state: S,
@@ -116,6 +227,7 @@ impl AsyncWorld {
         if !SPAWNER.is_set() {
             panic!("AsyncWorld::spawn_state_scoped can only be used in a bevy_defer future.")
         }
+        let location = Location::caller();
         with_world_ref(|world| match world.get_resource::<State<S>>() {
             Some(s) if s.get() == &state => Ok(()),
             _ => Err(AccessError::NotInState {
@@ -127,7 +239,7 @@ impl AsyncWorld {
                 res.tasks
                     .entry(state)
                     .or_default()
-                    .push(SPAWNER.with(|s| s.spawn(fut)));
+                    .push(SPAWNER.with(|s| s.spawn_located(TaskOptions::default(), location, fut)));
             } else {
                 error!(
                     "Cannot spawn state scoped futures without `react_to_state::<{}>`.",
@@ -148,22 +260,77 @@ impl AsyncWorld {
     ///
     /// # Panic Handling
     ///
-    /// Due to the internals of `AsyncExecutor` we currently cannot report error messages of panics.
-    /// Use `panic=abort` to avoid unwinding or choose an error based approach.
+    /// Panics are logged and reported as [`TaskPanicked`](crate::TaskPanicked) messages.
+    #[track_caller]
     pub fn spawn<T: 'static>(&self, fut: impl Future<Output = AccessResult<T>> + 'static) {
+        self.spawn_with(TaskOptions::default(), fut)
+    }
+
+    /// Spawn a `bevy_defer` compatible future with [`TaskOptions`] and logs errors.
+    ///
+    /// The spawned future will not be dropped until finished.
+    ///
+    /// # Panics
+    ///
+    /// If used outside a `bevy_defer` future.
+    #[track_caller]
+    pub fn spawn_with<T: 'static>(
+        &self,
+        options: impl Into<TaskOptions>,
+        fut: impl Future<Output = AccessResult<T>> + 'static,
+    ) {
         if !SPAWNER.is_set() {
             panic!("AsyncWorld::spawn can only be used in a bevy_defer future.")
         }
+        let location = Location::caller();
         SPAWNER.with(|s| {
-            let task = s.spawn(fut).fallible();
-            s.spawn(async move {
-                match task.await {
-                    Some(Err(e)) => error!("{e}"),
-                    None => error!("Task panicked!"),
-                    Some(_) => (),
+            s.spawn_located(options.into(), location, async {
+                if let Err(e) = fut.await {
+                    error!("{e}")
                 }
             })
-            .detach();
+            .detach()
         });
     }
 }
+
+impl<E: VirtualEntity> AsyncEntity<E> {
+    /// Spawn a `bevy_defer` compatible future, the future is constrained to this entity
+    /// and will be cancelled when the entity is despawned or [`EntityScopedTasks`] is removed.
+    ///
+    /// Errors returned by the future are logged.
+    ///
+    /// # Errors
+    ///
+    /// If the entity does not exist.
+    ///
+    /// # Panics
+    ///
+    /// * If used outside a `bevy_defer` future.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let entity = AsyncWorld.spawn_bundle(Int(1));
+    /// entity.spawn_scoped(async {
+    ///     loop {
+    ///         AsyncWorld.yield_now().await;
+    ///     }
+    /// })?;
+    /// // Cancels the task.
+    /// entity.despawn();
+    /// # });
+    /// ```
+    #[track_caller]
+    pub fn spawn_scoped(&self, fut: impl Future<Output = AccessResult> + 'static) -> AccessResult {
+        if !SPAWNER.is_set() {
+            panic!("AsyncEntity::spawn_scoped can only be used in a bevy_defer future.")
+        }
+        let location = Location::caller();
+        with_world_mut(|world| {
+            let entity = self.0.try_get_entity(world)?;
+            SPAWNER.with(|s| spawn_entity_scoped_at(world, s, entity, fut, location))
+        })
+    }
+}
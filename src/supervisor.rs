@@ -0,0 +1,166 @@
+//! Tasks that are restarted when they fail.
+use async_executor::Task;
+use bevy::log::warn;
+use std::future::Future;
+use std::panic::Location;
+use std::time::Duration;
+
+use crate::executor::SPAWNER;
+use crate::registry::TaskOptions;
+use crate::{AccessResult, AsyncWorld};
+
+/// Determines when a task spawned by [`AsyncWorld::spawn_supervised`] is restarted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Restart the task whenever it completes, successful or not.
+    Always,
+    /// Restart the task if it returns an error, up to `max_retries` times.
+    OnError {
+        /// Maximum number of restarts before giving up.
+        max_retries: usize,
+    },
+    /// Restart the task if it returns an error, up to `max_retries` times,
+    /// sleeping before each restart.
+    ///
+    /// The delay starts at `initial` and doubles after each restart, up to `max`.
+    Backoff {
+        /// Delay before the first restart.
+        initial: Duration,
+        /// Maximum delay between restarts.
+        max: Duration,
+        /// Maximum number of restarts before giving up.
+        max_retries: usize,
+    },
+}
+
+impl RestartPolicy {
+    /// Restart on error indefinitely.
+    pub const fn on_error() -> Self {
+        RestartPolicy::OnError {
+            max_retries: usize::MAX,
+        }
+    }
+
+    /// Restart on error indefinitely with exponential backoff.
+    pub const fn backoff(initial: Duration, max: Duration) -> Self {
+        RestartPolicy::Backoff {
+            initial,
+            max,
+            max_retries: usize::MAX,
+        }
+    }
+
+    /// Set the maximum number of restarts, has no effect on [`RestartPolicy::Always`].
+    pub const fn with_max_retries(self, retries: usize) -> Self {
+        match self {
+            RestartPolicy::Always => RestartPolicy::Always,
+            RestartPolicy::OnError { .. } => RestartPolicy::OnError {
+                max_retries: retries,
+            },
+            RestartPolicy::Backoff { initial, max, .. } => RestartPolicy::Backoff {
+                initial,
+                max,
+                max_retries: retries,
+            },
+        }
+    }
+
+    fn max_retries(&self) -> usize {
+        match self {
+            RestartPolicy::Always => usize::MAX,
+            RestartPolicy::OnError { max_retries } => *max_retries,
+            RestartPolicy::Backoff { max_retries, .. } => *max_retries,
+        }
+    }
+
+    fn delay(&self, retries: usize) -> Option<Duration> {
+        match self {
+            RestartPolicy::Backoff { initial, max, .. } => Some(
+                initial
+                    .saturating_mul(2u32.saturating_pow(retries.min(32) as u32))
+                    .min(*max),
+            ),
+            _ => None,
+        }
+    }
+}
+
+impl AsyncWorld {
+    /// Spawn a `bevy_defer` compatible future created by `factory`,
+    /// and create and run a new one according to the [`RestartPolicy`] when it completes.
+    ///
+    /// The returned [`Task`] completes with the result of the last run,
+    /// i.e. when the future succeeds or the policy runs out of retries.
+    ///
+    /// Restarts happen on the next frame at the earliest.
+    ///
+    /// # Handle
+    ///
+    /// If dropped, the supervisor and the running future will be dropped by the executor.
+    /// Use [`Task::detach`] to run it in the background.
+    ///
+    /// # Panics
+    ///
+    /// * If used outside a `bevy_defer` future.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let mut attempts = 0;
+    /// let result = AsyncWorld.spawn_supervised(
+    ///     move || {
+    ///         attempts += 1;
+    ///         let attempt = attempts;
+    ///         async move {
+    ///             if attempt < 3 {
+    ///                 Err(AccessError::Custom("network error"))
+    ///             } else {
+    ///                 Ok(())
+    ///             }
+    ///         }
+    ///     },
+    ///     RestartPolicy::backoff(
+    ///         std::time::Duration::from_millis(1),
+    ///         std::time::Duration::from_millis(10),
+    ///     ),
+    /// ).await;
+    /// assert!(result.is_ok());
+    /// # });
+    /// ```
+    #[track_caller]
+    pub fn spawn_supervised<F: Future<Output = AccessResult> + 'static>(
+        &self,
+        mut factory: impl FnMut() -> F + 'static,
+        policy: RestartPolicy,
+    ) -> Task<AccessResult> {
+        if !SPAWNER.is_set() {
+            panic!("AsyncWorld::spawn_supervised can only be used in a bevy_defer future.")
+        }
+        let location = Location::caller();
+        SPAWNER.with(|s| {
+            s.spawn_located(TaskOptions::default(), location, async move {
+                let mut retries = 0;
+                loop {
+                    let result = factory().await;
+                    match (&result, policy) {
+                        (Ok(()), RestartPolicy::Always) => (),
+                        (Ok(()), _) => return result,
+                        (Err(_), _) if retries >= policy.max_retries() => return result,
+                        (Err(e), _) => {
+                            warn!("Supervised task spawned at {location} failed, restarting: {e}")
+                        }
+                    }
+                    // Restart on the next frame at the earliest, so a future that
+                    // completes immediately does not block the executor.
+                    // `sleep` completes immediately on a zero delay, so yield instead.
+                    match policy.delay(retries) {
+                        Some(delay) if !delay.is_zero() => AsyncWorld.sleep(delay).await,
+                        _ => AsyncWorld.yield_now().await,
+                    }
+                    retries = retries.saturating_add(1);
+                }
+            })
+        })
+    }
+}
@@ -0,0 +1,156 @@
+//! Values stored per task on the [`AsyncExecutor`](crate::AsyncExecutor).
+use rustc_hash::FxHashMap;
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::executor::TASK_LOCALS;
+
+/// Storage of task local values of a task.
+#[derive(Debug, Default)]
+pub(crate) struct TaskLocals(RefCell<FxHashMap<TypeId, Rc<dyn Any>>>);
+
+impl TaskLocals {
+    /// Copy the task local values of the current task, if any.
+    pub(crate) fn inherit() -> Self {
+        if TASK_LOCALS.is_set() {
+            TASK_LOCALS.with(|locals| TaskLocals(RefCell::new(locals.0.borrow().clone())))
+        } else {
+            TaskLocals::default()
+        }
+    }
+}
+
+/// Declare [`TaskLocalKey`]s, values that are unique to each task.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// bevy_defer::task_local! {
+///     /// The player that owns the task.
+///     pub static PLAYER: Entity;
+///     static DEPTH: usize;
+/// }
+/// ```
+#[macro_export]
+macro_rules! task_local {
+    ($($(#[$($attr:tt)*])* $vis: vis static $name: ident: $ty: ty;)*) => {
+        $(
+            $(#[$($attr)*])*
+            $vis static $name: $crate::TaskLocalKey<$ty> = {
+                enum __Key {}
+                $crate::TaskLocalKey::new(::std::any::TypeId::of::<__Key>)
+            };
+        )*
+    };
+}
+
+/// A key to a value stored per task, declared via [`task_local!`](crate::task_local).
+///
+/// Values are set by the task itself and can be read anywhere inside the task,
+/// including [`Scope`](crate::Scope) children. Tasks spawned with
+/// [`TaskOptions::inherit_locals`](crate::TaskOptions::inherit_locals) start with a copy of
+/// the values of the spawning task.
+///
+/// # Panics
+///
+/// All methods except [`TaskLocalKey::is_available`] panic if not called
+/// inside a task on the [`AsyncExecutor`](crate::AsyncExecutor).
+///
+/// # Example
+///
+/// ```
+/// # bevy_defer::test_spawn!({
+/// bevy_defer::task_local! {
+///     static PLAYER: Entity;
+/// }
+///
+/// fn player() -> Entity {
+///     PLAYER.get().unwrap()
+/// }
+///
+/// let entity = AsyncWorld.spawn_bundle(()).id();
+/// PLAYER.set(entity);
+/// assert_eq!(player(), entity);
+/// let child = AsyncWorld.spawn_task_with(TaskOptions::new().inherit_locals(), async {
+///     player()
+/// });
+/// assert_eq!(child.await, entity);
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct TaskLocalKey<T: 'static> {
+    id: fn() -> TypeId,
+    p: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> TaskLocalKey<T> {
+    #[doc(hidden)]
+    pub const fn new(id: fn() -> TypeId) -> Self {
+        TaskLocalKey { id, p: PhantomData }
+    }
+
+    fn locals<U>(&self, f: impl FnOnce(&TaskLocals) -> U) -> U {
+        if !TASK_LOCALS.is_set() {
+            panic!("Task local values can only be accessed inside a `bevy_defer` task.")
+        }
+        TASK_LOCALS.with(f)
+    }
+
+    fn value(&self) -> Option<Rc<dyn Any>> {
+        self.locals(|locals| locals.0.borrow().get(&(self.id)()).cloned())
+    }
+
+    /// Returns `true` if called inside a task, where task local values are available.
+    pub fn is_available(&self) -> bool {
+        TASK_LOCALS.is_set()
+    }
+
+    /// Set the value for the current task, returns the previous value, if set.
+    pub fn set(&self, value: T) -> Option<Rc<T>> {
+        self.locals(|locals| locals.0.borrow_mut().insert((self.id)(), Rc::new(value)))
+            .and_then(|v| v.downcast().ok())
+    }
+
+    /// Remove the value for the current task, returns the previous value, if set.
+    pub fn remove(&self) -> Option<Rc<T>> {
+        self.locals(|locals| locals.0.borrow_mut().remove(&(self.id)()))
+            .and_then(|v| v.downcast().ok())
+    }
+
+    /// Returns `true` if the value is set for the current task.
+    pub fn is_set(&self) -> bool {
+        self.value().is_some()
+    }
+
+    /// Run a function on the value, returns `None` if not set.
+    pub fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Option<U> {
+        let value = self.value()?;
+        value.downcast_ref::<T>().map(f)
+    }
+
+    /// Run a function on the value.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not set.
+    pub fn with<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        match self.try_with(f) {
+            Some(result) => result,
+            None => panic!(
+                "Task local value {} is not set.",
+                std::any::type_name::<T>()
+            ),
+        }
+    }
+
+    /// Obtain a clone of the value, returns `None` if not set.
+    pub fn get(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.try_with(T::clone)
+    }
+}
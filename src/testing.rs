@@ -0,0 +1,173 @@
+//! Deterministic test harness for `bevy_defer`.
+use bevy::app::App;
+use bevy::diagnostic::FrameCount;
+use bevy::time::{Time, TimeUpdateStrategy, Virtual};
+use bevy::MinimalPlugins;
+use futures::FutureExt;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+
+use crate::{AsyncExecutor, AsyncPlugin, QueryQueue, Task};
+
+/// Maximum number of frames [`AsyncTestApp::run_until_stalled`] runs before panicking.
+const MAX_STALL_FRAMES: usize = 10_000;
+
+/// An [`App`] with [`MinimalPlugins`] and [`AsyncPlugin`] that is stepped manually,
+/// derefs to [`App`].
+///
+/// Virtual time only advances via [`AsyncTestApp::advance`],
+/// so tests do not depend on wall-clock time.
+///
+/// # Example
+///
+/// ```
+/// use bevy_defer::testing::AsyncTestApp;
+/// use bevy_defer::AsyncWorld;
+///
+/// let mut app = AsyncTestApp::new();
+/// let task = app.spawn(async {
+///     AsyncWorld.sleep(1.0).await;
+///     AsyncWorld.sleep_frames(2).await;
+///     42
+/// });
+/// app.run_until_stalled();
+/// app.assert_pending(&task);
+/// app.advance(std::time::Duration::from_secs(1));
+/// app.step_frames(2);
+/// assert_eq!(app.assert_completed(task), 42);
+/// ```
+#[derive(Debug)]
+pub struct AsyncTestApp(App);
+
+impl Default for AsyncTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncTestApp {
+    /// Create an [`AsyncTestApp`] with [`AsyncPlugin::default_settings`].
+    pub fn new() -> Self {
+        Self::with_plugin(AsyncPlugin::default_settings())
+    }
+
+    /// Create an [`AsyncTestApp`] with a configured [`AsyncPlugin`].
+    pub fn with_plugin(plugin: AsyncPlugin) -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(plugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_max_delta(Duration::MAX);
+        // Run startup schedules so the first step behaves like any other frame.
+        app.update();
+        AsyncTestApp(app)
+    }
+
+    /// Spawn a future on the [`AsyncExecutor`] and obtain a handle to check its result.
+    pub fn spawn<T: 'static>(&mut self, future: impl Future<Output = T> + 'static) -> Task<T> {
+        self.0
+            .world()
+            .non_send::<AsyncExecutor>()
+            .spawn_task(future)
+    }
+
+    /// Run a frame without advancing time.
+    pub fn step(&mut self) {
+        self.0.update();
+    }
+
+    /// Run `n` frames without advancing time.
+    pub fn step_frames(&mut self, n: u32) {
+        for _ in 0..n {
+            self.0.update();
+        }
+    }
+
+    /// Run a frame that advances virtual time by `duration`.
+    pub fn advance(&mut self, duration: Duration) {
+        self.0
+            .insert_resource(TimeUpdateStrategy::ManualDuration(duration));
+        self.0.update();
+        self.0
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+    }
+
+    /// Run frames until no task is polled in a frame and no
+    /// `sleep_frames` timers are pending, returns the number of frames run.
+    ///
+    /// Time does not advance, `sleep` only completes after [`AsyncTestApp::advance`].
+    ///
+    /// # Panics
+    ///
+    /// If not stalled after 10000 frames.
+    pub fn run_until_stalled(&mut self) -> usize {
+        for frame in 1..=MAX_STALL_FRAMES {
+            let polls = self.polls();
+            self.0.update();
+            if self.polls() == polls && !self.has_frame_timers() {
+                return frame;
+            }
+        }
+        panic!("AsyncTestApp is not stalled after {MAX_STALL_FRAMES} frames.")
+    }
+
+    /// Returns the value of `FrameCount`.
+    pub fn frame_count(&self) -> u32 {
+        self.0.world().resource::<FrameCount>().0
+    }
+
+    /// Returns `Time<Virtual>::elapsed`.
+    pub fn elapsed(&self) -> Duration {
+        self.0.world().resource::<Time<Virtual>>().elapsed()
+    }
+
+    /// Assert a task has completed and obtain its output.
+    ///
+    /// # Panics
+    ///
+    /// If the task has not completed.
+    #[track_caller]
+    pub fn assert_completed<T>(&mut self, mut task: Task<T>) -> T {
+        match (&mut task).now_or_never() {
+            Some(output) => output,
+            None => panic!("Expected task to be completed, but it is pending."),
+        }
+    }
+
+    /// Assert a task has not completed.
+    ///
+    /// # Panics
+    ///
+    /// If the task has completed.
+    #[track_caller]
+    pub fn assert_pending<T>(&self, task: &Task<T>) {
+        if task.is_finished() {
+            panic!("Expected task to be pending, but it has completed.")
+        }
+    }
+
+    fn polls(&self) -> u64 {
+        self.0.world().non_send::<AsyncExecutor>().0.polls.get()
+    }
+
+    fn has_frame_timers(&self) -> bool {
+        self.0.world().non_send::<QueryQueue>().has_frame_timers()
+    }
+}
+
+impl Deref for AsyncTestApp {
+    type Target = App;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AsyncTestApp {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
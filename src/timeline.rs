@@ -0,0 +1,105 @@
+//! Callbacks scheduled at absolute offsets from a start time.
+use futures::future::LocalBoxFuture;
+use futures::FutureExt;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::tween::AsSeconds;
+use crate::{AccessResult, AsyncWorld};
+
+/// A set of callbacks that run at absolute offsets from a start time.
+///
+/// Each callback starts when [`AsyncWorld::now`] reaches `start + offset`,
+/// regardless of how long previous callbacks took, so timing does not drift
+/// like chained calls to [`AsyncWorld::sleep`] would.
+///
+/// # Example
+///
+/// ```
+/// # bevy_defer::test_spawn!({
+/// let beats = std::cell::Cell::new(0);
+/// let beats = &beats;
+/// Timeline::new()
+///     .at(0.5, || async { beats.set(beats.get() + 1); Ok(()) })
+///     .at(1.0, || async { beats.set(beats.get() + 1); Ok(()) })
+///     .at(1.5, || async { beats.set(beats.get() + 1); Ok(()) })
+///     .run()
+///     .await?;
+/// assert_eq!(beats.get(), 3);
+/// # });
+/// ```
+#[must_use = "a Timeline does nothing unless you `.run()` it"]
+#[derive(Default)]
+pub struct Timeline<'t> {
+    start: Option<Duration>,
+    callbacks: Vec<(Duration, LocalBoxFuture<'t, AccessResult>)>,
+}
+
+impl std::fmt::Debug for Timeline<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Timeline")
+            .field("start", &self.start)
+            .field(
+                "offsets",
+                &self.callbacks.iter().map(|(at, _)| at).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<'t> Timeline<'t> {
+    /// Create an empty [`Timeline`] that starts when [`Timeline::run`] is called.
+    pub fn new() -> Self {
+        Timeline::default()
+    }
+
+    /// Set the start time, according to [`AsyncWorld::now`].
+    ///
+    /// Callbacks with offsets already passed run immediately.
+    pub fn with_start(mut self, start: Duration) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Run a callback at `offset` from the start time.
+    ///
+    /// The callback is not called until its offset is reached.
+    pub fn at<F: Future<Output = AccessResult> + 't>(
+        mut self,
+        offset: impl AsSeconds,
+        callback: impl FnOnce() -> F + 't,
+    ) -> Self {
+        self.callbacks.push((
+            offset.as_duration(),
+            async move { callback().await }.boxed_local(),
+        ));
+        self
+    }
+
+    /// Run all callbacks concurrently at their offsets,
+    /// completes when all of them have completed.
+    ///
+    /// # Errors
+    ///
+    /// If any callback returns an error, the remaining callbacks are cancelled
+    /// and the first error is returned.
+    ///
+    /// # Panics
+    ///
+    /// If used outside a `bevy_defer` future.
+    pub async fn run(self) -> AccessResult {
+        let start = self.start.unwrap_or_else(|| AsyncWorld.now());
+        let callbacks = self.callbacks;
+        AsyncWorld
+            .scope(|scope| async move {
+                for (offset, callback) in callbacks {
+                    scope.spawn(async move {
+                        AsyncWorld.sleep_until(start + offset).await;
+                        callback.await
+                    });
+                }
+                Ok(())
+            })
+            .await
+    }
+}
@@ -0,0 +1,125 @@
+//! Timeout combinators driven by game time.
+use futures::future::{select, Either};
+use std::future::Future;
+use std::pin::pin;
+use std::time::Duration;
+
+use crate::tween::AsSeconds;
+use crate::{AccessError, AccessResult, AsyncWorld};
+
+/// Race a future against a timer.
+async fn race<T>(
+    future: impl Future<Output = T>,
+    timer: impl Future<Output = ()> + Unpin,
+) -> AccessResult<T> {
+    match select(pin!(future), timer).await {
+        Either::Left((value, _)) => Ok(value),
+        Either::Right(_) => Err(AccessError::Timeout),
+    }
+}
+
+impl AsyncWorld {
+    /// Run a future until completion or until the duration has passed,
+    /// according to the same clock as [`AsyncWorld::sleep`].
+    ///
+    /// The future is dropped if timed out.
+    ///
+    /// # Errors
+    ///
+    /// [`AccessError::Timeout`] if timed out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let result = AsyncWorld.timeout(0.1, AsyncWorld.sleep(10.0)).await;
+    /// assert_eq!(result, Err(AccessError::Timeout));
+    /// # });
+    /// ```
+    pub fn timeout<T>(
+        &self,
+        duration: impl AsSeconds,
+        future: impl Future<Output = T>,
+    ) -> impl Future<Output = AccessResult<T>> {
+        race(future, self.sleep(duration))
+    }
+
+    /// Run a future until completion or until `Time<Virtual>::elapsed` reaches `at`,
+    /// according to the same clock as [`AsyncWorld::sleep_until`].
+    ///
+    /// The future is dropped if timed out.
+    ///
+    /// # Errors
+    ///
+    /// [`AccessError::Timeout`] if timed out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let at = AsyncWorld.now() + std::time::Duration::from_secs(1);
+    /// AsyncWorld.deadline(at, AsyncWorld.sleep_frames(1)).await?;
+    /// # });
+    /// ```
+    pub fn deadline<T>(
+        &self,
+        at: Duration,
+        future: impl Future<Output = T>,
+    ) -> impl Future<Output = AccessResult<T>> {
+        race(future, self.sleep_until(at))
+    }
+
+    /// Run a future until completion or until some frames have passed,
+    /// according to the same frame count as [`AsyncWorld::sleep_frames`].
+    ///
+    /// The future is dropped if timed out.
+    ///
+    /// # Errors
+    ///
+    /// [`AccessError::Timeout`] if timed out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let result = AsyncWorld.timeout_frames(2, AsyncWorld.sleep_frames(4)).await;
+    /// assert_eq!(result, Err(AccessError::Timeout));
+    /// # });
+    /// ```
+    pub fn timeout_frames<T>(
+        &self,
+        frames: u32,
+        future: impl Future<Output = T>,
+    ) -> impl Future<Output = AccessResult<T>> {
+        race(future, self.sleep_frames(frames))
+    }
+}
+
+/// Extension for adding timeouts to futures like `ChannelOut` and `InterpolateOut`.
+///
+/// # Panics
+///
+/// If used outside a `bevy_defer` future.
+///
+/// # Example
+///
+/// ```
+/// # bevy_defer::test_spawn!({
+/// let entity = AsyncWorld.spawn_bundle(Int(1));
+/// let result = entity.component::<Int>().watch(|x| (x.0 == 2).then_some(x.0)).timeout(0.1).await;
+/// assert_eq!(result, Err(AccessError::Timeout));
+/// # });
+/// ```
+pub trait TimeoutExt: Future + Sized {
+    /// Equivalent to [`AsyncWorld::timeout`].
+    fn timeout(self, duration: impl AsSeconds) -> impl Future<Output = AccessResult<Self::Output>> {
+        AsyncWorld.timeout(duration, self)
+    }
+
+    /// Equivalent to [`AsyncWorld::timeout_frames`].
+    fn timeout_frames(self, frames: u32) -> impl Future<Output = AccessResult<Self::Output>> {
+        AsyncWorld.timeout_frames(frames, self)
+    }
+}
+
+impl<F: Future> TimeoutExt for F {}
@@ -0,0 +1,114 @@
+//! Cancellable blocking operations with progress reporting.
+use async_shared::Value;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use futures::channel::oneshot;
+use futures::FutureExt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::cancellation::{CancelOnDropSync, SyncCancellation};
+use crate::AsyncWorld;
+
+/// Context of a blocking operation started by [`AsyncWorld::unblock_with`].
+#[derive(Debug)]
+pub struct UnblockContext {
+    cancel: SyncCancellation,
+    progress: Value<f32>,
+}
+
+impl UnblockContext {
+    /// Returns `true` if the [`Unblock`] future is dropped or cancelled,
+    /// the operation should return as soon as possible.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// Report progress, usually between `0.0` and `1.0`, to [`Unblock::progress`].
+    pub fn report_progress(&self, progress: f32) {
+        self.progress.write(progress)
+    }
+}
+
+/// Future for [`AsyncWorld::unblock_with`].
+///
+/// Dropping this future signals cancellation to the operation.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Unblock<T> {
+    receiver: oneshot::Receiver<T>,
+    progress: Value<f32>,
+    cancel: CancelOnDropSync,
+    _handle: Task<()>,
+}
+
+impl<T> Unblock<T> {
+    /// Signal cancellation to the operation without dropping this future.
+    pub fn cancel(&self) {
+        self.cancel.0.cancel()
+    }
+
+    /// Obtain the latest progress reported by the operation,
+    /// use `into_stream` to stream changes.
+    pub fn progress(&self) -> Value<f32> {
+        self.progress.clone_init()
+    }
+}
+
+impl<T> Future for Unblock<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.receiver
+            .poll_unpin(cx)
+            .map(|result| result.expect("unblock_with operation panicked."))
+    }
+}
+
+impl AsyncWorld {
+    /// Perform a blocking operation on [`AsyncComputeTaskPool`]
+    /// that can observe cancellation and report progress through an [`UnblockContext`].
+    ///
+    /// Dropping the returned [`Unblock`] signals cancellation,
+    /// the operation is responsible for checking [`UnblockContext::is_cancelled`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// let job = AsyncWorld.unblock_with(|ctx| {
+    ///     for i in 0..100 {
+    ///         if ctx.is_cancelled() {
+    ///             return None;
+    ///         }
+    ///         ctx.report_progress(i as f32 / 100.0);
+    ///     }
+    ///     Some(42)
+    /// });
+    /// let progress = job.progress();
+    /// assert_eq!(job.await, Some(42));
+    /// assert_eq!(progress.read(), Some(0.99));
+    /// # });
+    /// ```
+    pub fn unblock_with<T: Send + Sync + 'static>(
+        &self,
+        f: impl FnOnce(&UnblockContext) -> T + Send + Sync + 'static,
+    ) -> Unblock<T> {
+        let (send, receiver) = oneshot::channel();
+        let cancel = SyncCancellation::new();
+        let progress = Value::new();
+        let context = UnblockContext {
+            cancel: cancel.clone(),
+            progress: progress.clone_raw(),
+        };
+        let handle = AsyncComputeTaskPool::get().spawn(async move {
+            let _ = send.send(f(&context));
+        });
+        Unblock {
+            receiver,
+            progress,
+            cancel: cancel.cancel_on_drop(),
+            _handle: handle,
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! Event driven alternatives to `watch` for component changes.
+use bevy::ecs::change_detection::{DetectChanges, DetectChangesMut, Tick};
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::{Entities, Entity};
+use bevy::ecs::lifecycle::{Add, Remove};
+use bevy::ecs::observer::On;
+use bevy::ecs::query::Changed;
+use bevy::ecs::resource::Resource;
+use bevy::ecs::schedule::Schedules;
+use bevy::ecs::system::{Query, ResMut};
+use bevy::ecs::world::{Mut, World};
+use event_listener::{Event, EventListener};
+use futures::future::Either;
+use rustc_hash::FxHashMap;
+use std::future::{ready, Future};
+use std::marker::PhantomData;
+
+use crate::access::get_entity::VirtualEntity;
+use crate::access::AsyncEntity;
+use crate::executor::{with_world_mut, with_world_ref};
+use crate::{AccessError, AccessResult, BeforeAsyncExecutor};
+
+/// Futures waiting on [`AsyncEntity::watch_added`], [`AsyncEntity::watch_removed`]
+/// and [`AsyncEntity::watch_changed`] of a component.
+#[derive(Debug, Resource)]
+pub(crate) struct ComponentWatchers<C: Component> {
+    added: FxHashMap<Entity, Event>,
+    removed: FxHashMap<Entity, Event>,
+    changed: FxHashMap<Entity, Event>,
+    p: PhantomData<C>,
+}
+
+impl<C: Component> Default for ComponentWatchers<C> {
+    fn default() -> Self {
+        ComponentWatchers {
+            added: FxHashMap::default(),
+            removed: FxHashMap::default(),
+            changed: FxHashMap::default(),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C: Component> ComponentWatchers<C> {
+    /// Obtain the watchers of `C`, adding its observers and system on first use.
+    fn get(world: &mut World) -> Mut<'_, Self> {
+        if !world.contains_resource::<Self>() {
+            world.init_resource::<Self>();
+            world.add_observer(on_add::<C>);
+            world.add_observer(on_remove::<C>);
+            world
+                .resource_mut::<Schedules>()
+                .add_systems(BeforeAsyncExecutor, run_changed_watchers::<C>);
+        }
+        world.resource_mut::<Self>()
+    }
+}
+
+fn on_add<C: Component>(event: On<Add, C>, mut watchers: ResMut<ComponentWatchers<C>>) {
+    if let Some(event) = watchers.added.remove(&event.entity) {
+        event.notify(usize::MAX);
+    }
+}
+
+fn on_remove<C: Component>(event: On<Remove, C>, mut watchers: ResMut<ComponentWatchers<C>>) {
+    if let Some(event) = watchers.removed.remove(&event.entity) {
+        event.notify(usize::MAX);
+    }
+    // Wakes `watch_changed` futures to wait for `C` to be added again, or to return an error.
+    if let Some(event) = watchers.changed.remove(&event.entity) {
+        event.notify(usize::MAX);
+    }
+}
+
+/// Wakes `watch_changed` futures of entities whose `C` changed since the last run,
+/// and `watch_added` futures of despawned entities.
+fn run_changed_watchers<C: Component>(
+    mut watchers: ResMut<ComponentWatchers<C>>,
+    query: Query<Entity, Changed<C>>,
+    entities: &Entities,
+) {
+    if watchers.changed.is_empty() && watchers.added.is_empty() {
+        return;
+    }
+    let watchers = watchers.bypass_change_detection();
+    // Despawned, the future returns an error on wake.
+    watchers.added.retain(|entity, event| {
+        if entities.contains(*entity) {
+            true
+        } else {
+            event.notify(usize::MAX);
+            false
+        }
+    });
+    if watchers.changed.is_empty() {
+        return;
+    }
+    for entity in &query {
+        if let Some(event) = watchers.changed.remove(&entity) {
+            event.notify(usize::MAX);
+        }
+    }
+}
+
+impl<E: VirtualEntity> AsyncEntity<E> {
+    /// Wait until component `C` is added to the entity, resolves immediately if already present.
+    ///
+    /// Unlike `watch`, this is driven by an observer and does not run every frame.
+    ///
+    /// # Errors
+    ///
+    /// If the entity is despawned before `C` is added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # let entity = AsyncWorld.spawn_bundle(Int(1));
+    /// entity.watch_added::<Int>().await?;
+    /// # });
+    /// ```
+    pub fn watch_added<C: Component>(&self) -> impl Future<Output = AccessResult> + 'static {
+        let listener = with_world_mut(|world| {
+            let entity = self.0.try_get_entity(world)?;
+            let entity_ref = world
+                .get_entity(entity)
+                .map_err(|_| AccessError::EntityNotFound(entity))?;
+            if entity_ref.contains::<C>() {
+                return Ok(None);
+            }
+            let mut watchers = ComponentWatchers::<C>::get(world);
+            Ok(Some((
+                entity,
+                watchers.added.entry(entity).or_default().listen(),
+            )))
+        });
+        wait_for(listener)
+    }
+
+    /// Wait until component `C` is removed from the entity or the entity is despawned,
+    /// resolves immediately if not present.
+    ///
+    /// Unlike `watch`, this is driven by an observer and does not run every frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # let entity = AsyncWorld.spawn_bundle(Int(1));
+    /// entity.remove::<Int>()?;
+    /// entity.watch_removed::<Int>().await;
+    /// # });
+    /// ```
+    pub fn watch_removed<C: Component>(&self) -> impl Future<Output = ()> + 'static {
+        let listener = with_world_mut(|world| {
+            let entity = self.0.try_get_entity(world).ok()?;
+            if !world.get_entity(entity).ok()?.contains::<C>() {
+                return None;
+            }
+            let mut watchers = ComponentWatchers::<C>::get(world);
+            Some(watchers.removed.entry(entity).or_default().listen())
+        });
+        async move {
+            if let Some(listener) = listener {
+                listener.await;
+            }
+        }
+    }
+
+    /// Wait until component `C` on the entity is changed or inserted
+    /// after this function is called.
+    ///
+    /// Unlike `watch`, this only wakes when `C` on the entity is changed, added or removed.
+    ///
+    /// # Errors
+    ///
+    /// If the entity is despawned before `C` is changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # bevy_defer::test_spawn!({
+    /// # let entity = AsyncWorld.spawn_bundle(Int(1));
+    /// let changed = entity.watch_changed::<Int>();
+    /// entity.component::<Int>().get_mut(|x| x.0 = 2)?;
+    /// changed.await?;
+    /// # });
+    /// ```
+    pub fn watch_changed<C: Component>(&self) -> impl Future<Output = AccessResult> + 'static {
+        let watch = with_world_mut(|world| {
+            let entity = self.0.try_get_entity(world)?;
+            world
+                .get_entity(entity)
+                .map_err(|_| AccessError::EntityNotFound(entity))?;
+            // Changes made after this point have a newer tick.
+            Ok((entity, world.increment_change_tick()))
+        });
+        async move {
+            let (entity, since) = watch?;
+            // Futures on the same entity share an `Event`, so check the tick on wake.
+            while let Some(listener) =
+                with_world_mut(|world| listen_changed::<C>(world, entity, since))?
+            {
+                listener.await;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Returns `None` if `C` on the entity changed after `since`,
+/// otherwise listen for `C` to be changed, added or removed.
+fn listen_changed<C: Component>(
+    world: &mut World,
+    entity: Entity,
+    since: Tick,
+) -> AccessResult<Option<EventListener>> {
+    let this_run = world.change_tick();
+    let entity_ref = world
+        .get_entity(entity)
+        .map_err(|_| AccessError::EntityNotFound(entity))?;
+    let present = match entity_ref.get_ref::<C>() {
+        Some(component) if component.last_changed().is_newer_than(since, this_run) => {
+            return Ok(None)
+        }
+        Some(_) => true,
+        None => false,
+    };
+    let mut watchers = ComponentWatchers::<C>::get(world);
+    // If not present, wait for `C` to be added, this also checks for despawns.
+    let event = match present {
+        true => watchers.changed.entry(entity).or_default(),
+        false => watchers.added.entry(entity).or_default(),
+    };
+    Ok(Some(event.listen()))
+}
+
+/// Wait for a listener, then check if the entity still exists.
+fn wait_for(
+    listener: AccessResult<Option<(Entity, EventListener)>>,
+) -> impl Future<Output = AccessResult> + 'static {
+    match listener {
+        Err(e) => Either::Left(ready(Err(e))),
+        Ok(None) => Either::Left(ready(Ok(()))),
+        Ok(Some((entity, listener))) => Either::Right(async move {
+            listener.await;
+            with_world_ref(|world| match world.get_entity(entity) {
+                Ok(_) => Ok(()),
+                Err(_) => Err(AccessError::EntityNotFound(entity)),
+            })
+        }),
+    }
+}
@@ -0,0 +1,114 @@
+use bevy::app::AppLabel;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_defer::{AccessError, AsyncExtension, AsyncPlugin, AsyncWorld, AsyncWorlds};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, AppLabel)]
+struct MainWorld;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, AppLabel)]
+struct Simulation;
+
+#[derive(Debug, Default, Resource)]
+struct Score(u32);
+
+#[test]
+pub fn bridge_between_apps() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    let mut simulation = App::new();
+    simulation.add_plugins(MinimalPlugins);
+    simulation.add_plugins(AsyncPlugin::default_settings());
+    simulation.init_resource::<Score>();
+    let worlds = AsyncWorlds::default();
+    worlds.connect(MainWorld, app.world_mut());
+    worlds.connect(Simulation, simulation.world_mut());
+    assert!(worlds.contains(Simulation));
+
+    let result = Rc::new(Cell::new(None));
+    let r = result.clone();
+    app.spawn_task(async move {
+        AsyncWorld
+            .in_world(Simulation, |world| {
+                // Runs a future on the simulation's executor, which reports back to the main world.
+                world.spawn_task(async {
+                    AsyncWorld.resource::<Score>().get_mut(|s| s.0 = 5)?;
+                    AsyncWorld
+                        .in_world(MainWorld, |world| world.insert_resource(Score(6)))
+                        .await
+                });
+            })
+            .await?;
+        AsyncWorld.resource::<Score>().watch(|s| Some(s.0)).await?;
+        let score = AsyncWorld
+            .in_world(Simulation, |world| world.resource::<Score>().0)
+            .await?;
+        r.set(Some(score));
+        Ok(())
+    });
+    for _ in 0..8 {
+        app.update();
+        simulation.update();
+    }
+    assert_eq!(result.get(), Some(5));
+    assert_eq!(app.world().resource::<Score>().0, 6);
+}
+
+#[test]
+pub fn bridge_sub_app() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    let mut sub_app = SubApp::new();
+    sub_app.update_schedule = Some(Update.intern());
+    sub_app.init_resource::<AppTypeRegistry>();
+    sub_app.init_resource::<Time>();
+    sub_app.add_plugins(AsyncPlugin::default_settings());
+    sub_app.init_resource::<Score>();
+    app.insert_sub_app(Simulation, sub_app);
+    let worlds = AsyncWorlds::default();
+    worlds.connect(MainWorld, app.world_mut());
+    worlds.connect(Simulation, app.sub_app_mut(Simulation).world_mut());
+
+    app.spawn_task(async move {
+        AsyncWorld
+            .in_world(Simulation, |world| {
+                world.spawn_task(async { AsyncWorld.resource::<Score>().get_mut(|s| s.0 += 1) });
+            })
+            .await
+    });
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(app.sub_app(Simulation).world().resource::<Score>().0, 1);
+}
+
+#[test]
+pub fn bridge_not_found() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    let results = Rc::new(RefCell::new(Vec::new()));
+    let r = results.clone();
+    app.spawn_task(async move {
+        let result = AsyncWorld.in_world(Simulation, |_| ()).await;
+        r.borrow_mut().push(result);
+        let worlds = AsyncWorlds::default();
+        AsyncWorld.run(|world| {
+            let mut simulation = App::new();
+            simulation.add_plugins(MinimalPlugins);
+            simulation.add_plugins(AsyncPlugin::default_settings());
+            worlds.connect(MainWorld, world);
+            worlds.connect(Simulation, simulation.world_mut());
+        });
+        // The simulation has been dropped.
+        let result = AsyncWorld.in_world(Simulation, |_| ()).await;
+        r.borrow_mut().push(result);
+        Ok(())
+    });
+    app.update();
+    assert_eq!(*results.borrow(), vec![Err(AccessError::WorldNotFound); 2]);
+}
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use bevy_defer::{AsyncExecutor, AsyncExtension, AsyncPlugin, AsyncWorld};
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+pub fn tick_budget_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings().with_tick_budget(4));
+    app.add_plugins(MinimalPlugins);
+    let count = Rc::new(Cell::new(0));
+    for _ in 0..10 {
+        let count = count.clone();
+        app.spawn_task(async move {
+            count.set(count.get() + 1);
+            Ok(())
+        });
+    }
+    app.update();
+    assert_eq!(count.get(), 4);
+    assert_eq!(app.world().non_send::<AsyncExecutor>().deferred_tasks(), 6);
+    app.update();
+    assert_eq!(count.get(), 8);
+    assert_eq!(app.world().non_send::<AsyncExecutor>().deferred_tasks(), 2);
+    app.update();
+    assert_eq!(count.get(), 10);
+    assert_eq!(app.world().non_send::<AsyncExecutor>().deferred_tasks(), 0);
+}
+
+#[test]
+pub fn unlimited_budget_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let count = Rc::new(Cell::new(0));
+    for _ in 0..10 {
+        let count = count.clone();
+        app.spawn_task(async move {
+            AsyncWorld.yield_now().await;
+            count.set(count.get() + 1);
+            Ok(())
+        });
+    }
+    app.update();
+    app.update();
+    assert_eq!(count.get(), 10);
+    assert_eq!(app.world().non_send::<AsyncExecutor>().deferred_tasks(), 0);
+}
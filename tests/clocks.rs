@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_defer::{AsyncExtension, AsyncPlugin, AsyncWorld};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Debug, Default)]
+struct UiClock;
+
+fn advance_ui_clock(mut time: ResMut<Time<UiClock>>) {
+    time.advance_by(Duration::from_millis(250));
+}
+
+#[test]
+pub fn clock_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.init_resource::<Time<UiClock>>();
+    app.add_systems(PreUpdate, advance_ui_clock);
+    let virt = Rc::new(Cell::new(false));
+    let real = Rc::new(Cell::new(false));
+    let ui = Rc::new(Cell::new(false));
+    let v = virt.clone();
+    let r = real.clone();
+    let u = ui.clone();
+    app.spawn_task(async move {
+        AsyncWorld.sleep(1.0).await;
+        v.set(true);
+        Ok(())
+    });
+    app.spawn_task(async move {
+        AsyncWorld.sleep_real(1.0).await;
+        r.set(true);
+        Ok(())
+    });
+    app.spawn_task(async move {
+        AsyncWorld.sleep_on::<UiClock>(1.0).await;
+        u.set(true);
+        Ok(())
+    });
+    app.update();
+    app.world_mut().resource_mut::<Time<Virtual>>().pause();
+    for _ in 0..6 {
+        app.update();
+    }
+    assert!(!virt.get());
+    assert!(real.get());
+    assert!(ui.get());
+}
@@ -0,0 +1,38 @@
+use bevy::diagnostic::{DiagnosticPath, DiagnosticsPlugin, DiagnosticsStore};
+use bevy::prelude::*;
+use bevy_defer::{AsyncDiagnosticsPlugin, AsyncExtension, AsyncPlugin, AsyncWorld};
+
+fn value(app: &App, path: &DiagnosticPath) -> f64 {
+    app.world()
+        .resource::<DiagnosticsStore>()
+        .get_measurement(path)
+        .unwrap()
+        .value
+}
+
+#[test]
+pub fn diagnostics_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(DiagnosticsPlugin);
+    app.add_plugins(AsyncDiagnosticsPlugin);
+    for i in 0..3 {
+        app.spawn_task(async move {
+            AsyncWorld.sleep_frames(5 + i).await;
+            Ok(())
+        });
+    }
+    app.update();
+    assert_eq!(value(&app, &AsyncDiagnosticsPlugin::TASKS), 3.0);
+    assert_eq!(value(&app, &AsyncDiagnosticsPlugin::POLLS), 3.0);
+    assert_eq!(value(&app, &AsyncDiagnosticsPlugin::TIMERS), 3.0);
+    assert!(value(&app, &AsyncDiagnosticsPlugin::EXECUTOR_TIME) >= 0.0);
+    app.update();
+    assert_eq!(value(&app, &AsyncDiagnosticsPlugin::POLLS), 0.0);
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(value(&app, &AsyncDiagnosticsPlugin::TASKS), 0.0);
+    assert_eq!(value(&app, &AsyncDiagnosticsPlugin::TIMERS), 0.0);
+}
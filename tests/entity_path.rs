@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use bevy_defer::access::{AsyncEntity, EntityPath};
+use bevy_defer::testing::AsyncTestApp;
+use bevy_defer::{fetch, AccessError, AccessResult, AsyncWorld};
+
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+struct Int(i32);
+
+#[derive(Debug, Component)]
+#[relationship(relationship_target = Equipment)]
+struct EquippedBy(Entity);
+
+#[derive(Debug, Component)]
+#[relationship_target(relationship = EquippedBy)]
+struct Equipment(Vec<Entity>);
+
+#[test]
+pub fn entity_path_parse() {
+    let path = EntityPath::new("/Player//Armature/./#2/../Hand.R/");
+    assert_eq!(path.to_string(), "Player/Armature/#2/../Hand.R");
+    assert_eq!(path.root(), None);
+    assert_eq!(EntityPath::new("#x").to_string(), "#x");
+    assert_eq!(path, EntityPath::new("Player/Armature/#2/../Hand.R"));
+}
+
+#[test]
+pub fn entity_path_resolve() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let player = AsyncWorld.spawn_bundle((Name::new("Player"), Int(0)));
+        let armature = player.spawn_child(Name::new("Armature"))?;
+        let left = armature.spawn_child((Name::new("Hand.L"), Int(1)))?;
+        let right = armature.spawn_child((Name::new("Hand.R"), Int(2)))?;
+
+        let path = AsyncWorld.entity_path("Player/Armature/Hand.R");
+        assert_eq!(path.try_get_id()?, right.id());
+        assert_eq!(fetch!(path, Int).get(|x| x.0)?, 2);
+        let hand = EntityPath::new("Player/Armature/#0");
+        assert_eq!(fetch!(hand, &Int).get(|x| x.0)?, 1);
+        assert_eq!(fetch!(#hand).try_get_id()?, left.id());
+        assert_eq!(left.path("../Hand.R").try_get_id()?, right.id());
+        assert_eq!(left.path("../..").try_get_id()?, player.id());
+        assert_eq!(player.path("").try_get_id()?, player.id());
+
+        assert_eq!(
+            AsyncWorld.entity_path("Armature").try_get_id(),
+            Err(AccessError::NamedChildNotFound)
+        );
+        assert_eq!(
+            player.path("Armature/#2").try_get_id(),
+            Err(AccessError::ChildNotFound { index: 2 })
+        );
+        assert!(player.path("..").try_get_id().is_err());
+
+        // Paths can be stored and resolved later.
+        let stored: AsyncEntity<EntityPath> = player.path("Armature/Hand.L");
+        AsyncWorld.entity(left.id()).despawn();
+        assert!(stored.try_get_id().is_err());
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
+
+#[test]
+pub fn entity_path_relationship() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let knight = AsyncWorld.spawn_bundle(Name::new("Knight"));
+        let sword = AsyncWorld.spawn_bundle((Name::new("Sword"), EquippedBy(knight.id())));
+        let path = EntityPath::new("Knight/Sword").with_relationship::<Equipment>();
+        assert_eq!(AsyncEntity::from_virtual(path).try_get_id()?, sword.id());
+        let path = EntityPath::relative(sword.id(), "..").with_relationship::<Equipment>();
+        assert_eq!(AsyncEntity::from_virtual(path).try_get_id()?, knight.id());
+        // `Sword` is not a root of `Equipment`.
+        let path = EntityPath::new("Sword").with_relationship::<Equipment>();
+        assert!(AsyncEntity::from_virtual(path).try_get_id().is_err());
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
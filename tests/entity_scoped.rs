@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use bevy_defer::{
+    AsyncEntityCommandsExtension, AsyncExtension, AsyncPlugin, AsyncWorld, EntityScopedTasks,
+};
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+pub fn entity_scoped_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.spawn_task(async move {
+        let count = Rc::new(Cell::new(0));
+        let entity = AsyncWorld.spawn_empty();
+        let c = count.clone();
+        entity.spawn_scoped(async move {
+            loop {
+                c.set(c.get() + 1);
+                AsyncWorld.yield_now().await;
+            }
+        })?;
+        AsyncWorld.sleep_frames(4).await;
+        entity.despawn();
+        let value = count.get();
+        AsyncWorld.sleep_frames(4).await;
+        assert_eq!(count.get(), value);
+        assert_eq!(Rc::strong_count(&count), 1);
+
+        let entity = AsyncWorld.spawn_empty();
+        let c = count.clone();
+        entity.spawn_scoped(async move {
+            loop {
+                c.set(c.get() + 1);
+                AsyncWorld.yield_now().await;
+            }
+        })?;
+        AsyncWorld.sleep_frames(4).await;
+        entity.remove::<EntityScopedTasks>()?;
+        AsyncWorld.sleep_frames(1).await;
+        assert_eq!(Rc::strong_count(&count), 1);
+        assert!(entity.exists());
+        AsyncWorld.quit();
+        Ok(())
+    });
+    app.run();
+}
+
+#[derive(Component)]
+struct Marker;
+
+#[test]
+pub fn entity_scoped_commands_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.add_systems(Startup, |mut commands: Commands| {
+        commands
+            .spawn(Marker)
+            .spawn_entity_scoped(|entity| async move {
+                loop {
+                    AsyncWorld.yield_now().await;
+                    AsyncWorld.entity(entity).get(|_| ())?;
+                }
+            });
+    });
+    app.update();
+    app.update();
+    let mut query = app.world_mut().query_filtered::<Entity, With<Marker>>();
+    let entity = query.single(app.world()).unwrap();
+    assert_eq!(
+        app.world()
+            .get::<EntityScopedTasks>(entity)
+            .map(|x| x.len()),
+        Some(1)
+    );
+    app.world_mut().despawn(entity);
+    app.update();
+    assert!(app
+        .world()
+        .resource::<bevy_defer::TaskRegistry>()
+        .is_empty());
+}
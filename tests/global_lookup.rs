@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use bevy_defer::access::{AsyncEntity, ByName, ByQuery, Singleton};
+use bevy_defer::testing::AsyncTestApp;
+use bevy_defer::{AccessError, AccessResult, AsyncWorld};
+use std::any::type_name;
+
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+struct Player;
+
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+struct Enemy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+struct Unused;
+
+#[test]
+pub fn lookup_by_name() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let boss = AsyncEntity::from_virtual(ByName("Boss"));
+        assert_eq!(
+            boss.try_get_id(),
+            Err(AccessError::NoEntityFound {
+                query: type_name::<Name>()
+            })
+        );
+        let entity = AsyncWorld.spawn_bundle(Name::new("Boss"));
+        AsyncWorld.spawn_bundle(Name::new("Minion"));
+        assert_eq!(boss.try_get_id()?, entity.id());
+        let owned = AsyncEntity::from_virtual(ByName(String::from("Minion")));
+        AsyncWorld.spawn_bundle(Name::new("Minion"));
+        assert_eq!(
+            owned.try_get_id(),
+            Err(AccessError::TooManyEntities {
+                query: type_name::<Name>()
+            })
+        );
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
+
+#[test]
+pub fn lookup_singleton() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let player = AsyncWorld.entity_by::<Singleton<With<Player>>>();
+        let unused = AsyncWorld.entity_by::<Singleton<With<Unused>>>();
+        assert!(matches!(
+            unused.try_get_id(),
+            Err(AccessError::NoEntityFound { .. })
+        ));
+        assert!(matches!(
+            player.try_get_id(),
+            Err(AccessError::NoEntityFound { .. })
+        ));
+        let entity = AsyncWorld.spawn_bundle((Player, Transform::default()));
+        assert_eq!(player.try_get_id()?, entity.id());
+        player
+            .component::<Transform>()
+            .get_mut(|x| x.translation.x = 1.0)?;
+        assert_eq!(
+            entity.component::<Transform>().get(|x| x.translation.x)?,
+            1.0
+        );
+        AsyncWorld.spawn_bundle(Player);
+        assert!(matches!(
+            player.try_get_id(),
+            Err(AccessError::TooManyEntities { .. })
+        ));
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
+
+#[test]
+pub fn lookup_by_query() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let enemy = AsyncWorld.entity_by::<ByQuery<(With<Enemy>, Without<Player>)>>();
+        assert!(matches!(
+            enemy.try_get_id(),
+            Err(AccessError::NoEntityFound { .. })
+        ));
+        AsyncWorld.spawn_bundle((Enemy, Player));
+        assert!(enemy.try_get_id().is_err());
+        let e1 = AsyncWorld.spawn_bundle(Enemy);
+        let e2 = AsyncWorld.spawn_bundle(Enemy);
+        let found = enemy.try_get_id()?;
+        assert!(found == e1.id() || found == e2.id());
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_defer::{AsyncExtension, AsyncPlugin, AsyncWorld, QueryQueue, StreamTimeExt, TaskOptions};
+use futures::channel::mpsc;
+use futures::StreamExt;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        125,
+    )));
+    app
+}
+
+#[test]
+pub fn interval_test() {
+    let mut app = app();
+    let ticks = Rc::new(RefCell::new(Vec::new()));
+    let frame_ticks = Rc::new(RefCell::new(Vec::new()));
+    let (t, f) = (ticks.clone(), frame_ticks.clone());
+    app.spawn_task(async move {
+        let mut interval = AsyncWorld.interval(0.25);
+        while interval.next().await.is_some() {
+            t.borrow_mut().push(AsyncWorld.frame_count());
+        }
+        Ok(())
+    });
+    app.spawn_task(async move {
+        let mut interval = AsyncWorld.interval_frames(3);
+        while interval.next().await.is_some() {
+            f.borrow_mut().push(AsyncWorld.frame_count());
+        }
+        Ok(())
+    });
+    for _ in 0..10 {
+        app.update();
+    }
+    let gaps = |v: &[u32]| v.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+    assert!(ticks.borrow().len() >= 3);
+    assert!(gaps(&ticks.borrow()).iter().all(|x| *x == 2));
+    assert!(frame_ticks.borrow().len() >= 2);
+    assert!(gaps(&frame_ticks.borrow()).iter().all(|x| *x == 3));
+}
+
+#[test]
+pub fn throttle_debounce_test() {
+    let mut app = app();
+    let (sender, receiver) = mpsc::unbounded::<u32>();
+    let (sender2, receiver2) = mpsc::unbounded::<u32>();
+    let throttled = Rc::new(RefCell::new(Vec::new()));
+    let debounced = Rc::new(RefCell::new(Vec::new()));
+    let (t, d) = (throttled.clone(), debounced.clone());
+    app.spawn_task(async move {
+        let mut stream = receiver.throttle(0.5);
+        while let Some(item) = stream.next().await {
+            t.borrow_mut().push(item);
+        }
+        Ok(())
+    });
+    app.spawn_task(async move {
+        let mut stream = receiver2.debounce(0.5);
+        while let Some(item) = stream.next().await {
+            d.borrow_mut().push(item);
+        }
+        Ok(())
+    });
+    // One item per frame for 8 frames (1 second), then quiet.
+    for i in 0..8 {
+        sender.unbounded_send(i).unwrap();
+        sender2.unbounded_send(i).unwrap();
+        app.update();
+    }
+    assert_eq!(*throttled.borrow(), vec![0, 4]);
+    assert!(debounced.borrow().is_empty());
+    for _ in 0..6 {
+        app.update();
+    }
+    assert_eq!(*debounced.borrow(), vec![7]);
+    sender2.unbounded_send(8).unwrap();
+    drop(sender2);
+    app.update();
+    assert_eq!(*debounced.borrow(), vec![7, 8]);
+}
+
+#[test]
+pub fn interval_paused_test() {
+    let mut app = app();
+    let ticks = Rc::new(RefCell::new(Vec::new()));
+    let t = ticks.clone();
+    app.spawn_task_with(TaskOptions::new().with_tag("gameplay"), async move {
+        let mut interval = AsyncWorld.interval(0.25);
+        while interval.next().await.is_some() {
+            t.borrow_mut().push(AsyncWorld.frame_count());
+        }
+        Ok(())
+    });
+    for _ in 0..5 {
+        app.update();
+    }
+    app.world().non_send::<QueryQueue>().pause_tag("gameplay");
+    for _ in 0..7 {
+        app.update();
+    }
+    let before = ticks.borrow().len();
+    app.world().non_send::<QueryQueue>().resume_tag("gameplay");
+    for _ in 0..6 {
+        app.update();
+    }
+    let ticks = ticks.borrow();
+    let resumed = app.world().resource::<bevy::diagnostic::FrameCount>().0 - 6;
+    // The missed items are yielded once on resume, then every 2 frames.
+    assert_eq!(ticks[before..], [resumed, resumed + 2, resumed + 4]);
+}
+
+#[test]
+pub fn throttle_ready_test() {
+    let mut app = app();
+    let throttled = Rc::new(RefCell::new(0));
+    let t = throttled.clone();
+    app.spawn_task(async move {
+        let mut stream = futures::stream::repeat(()).throttle(0.5);
+        while stream.next().await.is_some() {
+            *t.borrow_mut() += 1;
+        }
+        Ok(())
+    });
+    // Does not block the executor on a source that is always ready.
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(*throttled.borrow(), 3);
+}
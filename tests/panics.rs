@@ -0,0 +1,50 @@
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy_defer::{AsyncExecutor, AsyncPlugin, AsyncWorld, TaskPanicked};
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
+
+#[test]
+pub fn panic_report_test() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let hook_reports = reports.clone();
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings().on_panic(move |panic| {
+        hook_reports.lock().unwrap().push(panic.clone());
+    }));
+    app.add_plugins(MinimalPlugins);
+    let executor = app.world().non_send::<AsyncExecutor>().clone();
+    executor.spawn_any_with("bad_task", async {
+        AsyncWorld.yield_now().await;
+        panic!("oh no: {}", 42);
+    });
+    let line = line!() - 4;
+    let task = executor.spawn_task(async {
+        AsyncWorld.yield_now().await;
+        panic!("static message");
+    });
+    app.update();
+    assert!(reports.lock().unwrap().is_empty());
+    app.update();
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 2);
+    let bad_task = reports
+        .iter()
+        .find(|x| x.name.as_deref() == Some("bad_task"))
+        .unwrap();
+    assert_eq!(bad_task.message.as_deref(), Some("oh no: 42"));
+    assert_eq!(bad_task.location.file(), file!());
+    assert_eq!(bad_task.location.line(), line);
+    let other = reports.iter().find(|x| x.name.is_none()).unwrap();
+    assert_eq!(other.message.as_deref(), Some("static message"));
+
+    let messages = app.world().resource::<Messages<TaskPanicked>>();
+    assert_eq!(messages.len(), 2);
+
+    // Panics are still propagated to the task handle.
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        futures::executor::block_on(task);
+    }));
+    assert!(result.is_err());
+}
@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_defer::{AsyncExtension, AsyncPlugin, AsyncWorld, QueryQueue, TaskOptions};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+fn pause(app: &mut App, tag: &'static str) {
+    app.world().non_send::<QueryQueue>().pause_tag(tag);
+}
+
+fn resume(app: &mut App, tag: &'static str) {
+    app.world().non_send::<QueryQueue>().resume_tag(tag);
+}
+
+#[test]
+pub fn pause_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let gameplay = Rc::new(Cell::new(0));
+    let ui = Rc::new(Cell::new(0));
+    let g = gameplay.clone();
+    app.spawn_task_with(TaskOptions::new().with_tag("gameplay"), async move {
+        loop {
+            g.set(g.get() + 1);
+            AsyncWorld.yield_now().await;
+        }
+    });
+    let u = ui.clone();
+    app.spawn_task_with(TaskOptions::new().with_tag("ui"), async move {
+        loop {
+            u.set(u.get() + 1);
+            AsyncWorld.yield_now().await;
+        }
+    });
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(gameplay.get(), 3);
+    assert_eq!(ui.get(), 3);
+    pause(&mut app, "gameplay");
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(gameplay.get(), 3);
+    assert_eq!(ui.get(), 6);
+    resume(&mut app, "gameplay");
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(gameplay.get(), 6);
+    assert_eq!(ui.get(), 9);
+}
+
+#[test]
+pub fn pause_timer_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let frames_done = Rc::new(Cell::new(false));
+    let time_done = Rc::new(Cell::new(false));
+    let f = frames_done.clone();
+    app.spawn_task_with(TaskOptions::new().with_tag("gameplay"), async move {
+        AsyncWorld.sleep_frames(5).await;
+        f.set(true);
+        Ok(())
+    });
+    let t = time_done.clone();
+    app.spawn_task_with(TaskOptions::new().with_tag("gameplay"), async move {
+        AsyncWorld.sleep(Duration::from_millis(450)).await;
+        t.set(true);
+        Ok(())
+    });
+    for _ in 0..3 {
+        app.update();
+    }
+    pause(&mut app, "gameplay");
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(!frames_done.get());
+    assert!(!time_done.get());
+    resume(&mut app, "gameplay");
+    app.update();
+    assert!(!frames_done.get());
+    assert!(!time_done.get());
+    for _ in 0..4 {
+        app.update();
+    }
+    assert!(frames_done.get());
+    assert!(time_done.get());
+}
+
+#[test]
+pub fn pause_multiple_tags_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let done = Rc::new(Cell::new(false));
+    let d = done.clone();
+    app.spawn_task_with(
+        TaskOptions::new().with_tag("ui").with_tag("gameplay"),
+        async move {
+            AsyncWorld.sleep(Duration::from_millis(450)).await;
+            d.set(true);
+            Ok(())
+        },
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+    // Pausing any tag stops the clock of the task.
+    pause(&mut app, "gameplay");
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(!done.get());
+    resume(&mut app, "gameplay");
+    app.update();
+    assert!(!done.get());
+    pause(&mut app, "ui");
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(!done.get());
+    resume(&mut app, "ui");
+    for _ in 0..4 {
+        app.update();
+    }
+    assert!(done.get());
+    assert!(!app.world().non_send::<QueryQueue>().is_tag_paused("ui"));
+}
+
+#[derive(Debug, Component)]
+pub struct Value(f32);
+
+#[test]
+pub fn pause_interpolate_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let entity = app.world_mut().spawn(Value(0.0)).id();
+    let done = Rc::new(Cell::new(false));
+    let d = done.clone();
+    app.spawn_task_with(TaskOptions::new().with_tag("gameplay"), async move {
+        AsyncWorld
+            .entity(entity)
+            .component::<Value>()
+            .interpolate_to(1.0, |v| v.0, |v, x| v.0 = x, |x| x, 1.0, ())
+            .await?;
+        d.set(true);
+        Ok(())
+    });
+    for _ in 0..4 {
+        app.update();
+    }
+    let value = app.world().get::<Value>(entity).unwrap().0;
+    assert!(value > 0.0 && value < 1.0);
+    pause(&mut app, "gameplay");
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(app.world().get::<Value>(entity).unwrap().0, value);
+    assert!(!done.get());
+    resume(&mut app, "gameplay");
+    app.update();
+    assert!(app.world().get::<Value>(entity).unwrap().0 > value);
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(app.world().get::<Value>(entity).unwrap().0, 1.0);
+    assert!(done.get());
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy_defer::{AsyncCommandsExtension, AsyncExtension, AsyncPlugin, TaskPriority};
+use std::sync::{Arc, Mutex};
+
+#[test]
+pub fn priority_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings().with_tick_budget(1));
+    app.add_plugins(MinimalPlugins);
+    let order = Arc::new(Mutex::new(Vec::new()));
+    for (name, priority) in [
+        ("background", TaskPriority::Background),
+        ("normal", TaskPriority::Normal),
+        ("high", TaskPriority::High),
+    ] {
+        for i in 0..2 {
+            let order = order.clone();
+            app.spawn_task_with(priority, async move {
+                order.lock().unwrap().push((name, i));
+                Ok(())
+            });
+        }
+    }
+    app.update();
+    // High priority tasks are not limited by the budget.
+    assert_eq!(
+        *order.lock().unwrap(),
+        vec![("high", 0), ("high", 1), ("normal", 0)]
+    );
+    app.update();
+    app.update();
+    app.update();
+    assert_eq!(
+        *order.lock().unwrap(),
+        vec![
+            ("high", 0),
+            ("high", 1),
+            ("normal", 0),
+            ("normal", 1),
+            ("background", 0),
+            ("background", 1)
+        ]
+    );
+}
+
+#[test]
+pub fn priority_commands_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings().with_tick_budget(1));
+    app.add_plugins(MinimalPlugins);
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let o = order.clone();
+    app.add_systems(Startup, move |mut commands: Commands| {
+        let a = o.clone();
+        commands.spawn_task(move || async move {
+            a.lock().unwrap().push("normal");
+            Ok(())
+        });
+        let b = o.clone();
+        commands.spawn_task_with(TaskPriority::High, move || async move {
+            b.lock().unwrap().push("high");
+            Ok(())
+        });
+    });
+    app.update();
+    assert_eq!(*order.lock().unwrap(), vec!["high", "normal"]);
+}
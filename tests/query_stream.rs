@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use bevy_defer::{AccessResult, AsyncExtension, AsyncPlugin, AsyncWorld};
+use futures::StreamExt;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, Component, PartialEq)]
+struct Int(i32);
+
+#[derive(Debug, Clone, Copy, Component, PartialEq)]
+struct Marker;
+
+#[test]
+pub fn query_iter_collect() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.world_mut().spawn((Int(1), Marker));
+    app.world_mut().spawn(Int(2));
+    app.world_mut().spawn((Int(3), Marker));
+    let result = Rc::new(RefCell::new(Vec::new()));
+    let r = result.clone();
+    app.spawn_task(async move {
+        let mut ints = AsyncWorld
+            .query_filtered::<&Int, With<Marker>>()
+            .iter_collect(|x| x.0);
+        ints.sort();
+        *r.borrow_mut() = ints;
+        Ok(())
+    });
+    app.update();
+    assert_eq!(*result.borrow(), vec![1, 3]);
+}
+
+#[test]
+pub fn query_wait_any() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    let entity = app.world_mut().spawn(Int(0)).id();
+    let found = Rc::new(RefCell::new(None));
+    let f = found.clone();
+    app.spawn_task(async move {
+        let item = AsyncWorld.query::<&Int>().wait_any(|x| x.0 >= 3).await;
+        *f.borrow_mut() = Some(item.id());
+        Ok(())
+    });
+    for _ in 0..3 {
+        app.update();
+        app.world_mut().get_mut::<Int>(entity).unwrap().0 += 1;
+        assert_eq!(*found.borrow(), None);
+    }
+    app.update();
+    assert_eq!(*found.borrow(), Some(entity));
+}
+
+#[test]
+pub fn query_stream_added() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.world_mut().spawn(Int(0));
+    let added = Rc::new(RefCell::new(Vec::new()));
+    let a = added.clone();
+    app.spawn_task(async move {
+        let mut stream = AsyncWorld
+            .query_filtered::<&Int, With<Marker>>()
+            .stream_added();
+        while let Some(item) = stream.next().await {
+            let value = item.entity().component::<Int>().get(|x| x.0)?;
+            a.borrow_mut().push(value);
+        }
+        AccessResult::Ok(())
+    });
+    app.update();
+    assert!(added.borrow().is_empty());
+    let e1 = app.world_mut().spawn((Int(1), Marker)).id();
+    let e2 = app.world_mut().spawn((Int(2), Marker)).id();
+    app.update();
+    app.update();
+    let mut values = added.borrow().clone();
+    values.sort();
+    assert_eq!(values, vec![1, 2]);
+    // Stops matching then matches again.
+    app.world_mut().entity_mut(e1).remove::<Marker>();
+    app.update();
+    app.world_mut().entity_mut(e1).insert(Marker);
+    app.world_mut().despawn(e2);
+    app.update();
+    app.update();
+    assert_eq!(added.borrow().len(), 3);
+    assert_eq!(added.borrow()[2], 1);
+}
@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+use bevy::reflect::structs::DynamicStruct;
+use bevy::reflect::Typed;
+use bevy_defer::testing::AsyncTestApp;
+use bevy_defer::{AccessError, AccessResult, AsyncWorld};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default)]
+struct Health {
+    current: f32,
+    max: f32,
+}
+
+#[derive(Debug, Clone, Copy, Component)]
+struct Opaque;
+
+/// Without `Default`, cannot be created from a partial value.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+struct Stamina {
+    current: f32,
+    max: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+#[component(immutable)]
+#[reflect(Component, Default)]
+struct Frozen(f32);
+
+const HEALTH: &str = "reflect::Health";
+
+#[test]
+pub fn reflect_get_and_patch() {
+    let mut app = AsyncTestApp::new();
+    app.register_type::<Health>();
+    let task = app.spawn(async {
+        let entity = AsyncWorld.spawn_bundle(Health {
+            current: 5.0,
+            max: 10.0,
+        });
+        let health = entity.reflect(HEALTH);
+        assert!(health.exists());
+        let max = health.get(|x| {
+            *x.reflect_ref()
+                .as_struct()
+                .unwrap()
+                .field("max")
+                .unwrap()
+                .try_downcast_ref::<f32>()
+                .unwrap()
+        })?;
+        assert_eq!(max, 10.0);
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("current", 8.0f32);
+        health.apply(&patch)?;
+        assert_eq!(
+            entity.component::<Health>().get(|x| *x)?,
+            Health {
+                current: 8.0,
+                max: 10.0
+            }
+        );
+
+        let mut wrong = DynamicStruct::default();
+        wrong.insert("current", "full");
+        assert!(matches!(
+            health.apply(&wrong),
+            Err(AccessError::DowncastFailed { .. })
+        ));
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
+
+#[test]
+pub fn reflect_insert_and_remove() {
+    let mut app = AsyncTestApp::new();
+    app.register_type::<Health>();
+    app.register_type::<Stamina>();
+    let task = app.spawn(async {
+        let entity = AsyncWorld.spawn_bundle(Opaque);
+        let health = entity.reflect(HEALTH);
+        assert!(matches!(
+            health.get(|_| ()),
+            Err(AccessError::ComponentNotFound { .. })
+        ));
+        health.insert(&Health {
+            current: 1.0,
+            max: 2.0,
+        })?;
+        assert_eq!(entity.component::<Health>().get(|x| x.max)?, 2.0);
+        assert!(matches!(
+            health.insert(&Transform::default()),
+            Err(AccessError::DowncastFailed { .. })
+        ));
+        // Missing `max`, cannot be converted to `Stamina`.
+        let stamina = entity.reflect("reflect::Stamina");
+        let mut partial = DynamicStruct::default();
+        partial.set_represented_type(Some(Stamina::type_info()));
+        partial.insert("current", 3.0f32);
+        assert_eq!(
+            stamina.insert(&partial),
+            Err(AccessError::DowncastFailed {
+                name: "reflect::Stamina"
+            })
+        );
+        assert!(!stamina.exists());
+        partial.insert("max", 5.0f32);
+        stamina.insert(&partial)?;
+        assert_eq!(
+            entity.component::<Stamina>().get(|x| *x)?,
+            Stamina {
+                current: 3.0,
+                max: 5.0
+            }
+        );
+        health.remove()?;
+        assert!(!health.exists());
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
+
+#[test]
+pub fn reflect_by_id() {
+    let mut app = AsyncTestApp::new();
+    app.register_type::<Health>();
+    let task = app.spawn(async {
+        let entity = AsyncWorld.spawn_bundle(Health::default());
+        let id = AsyncWorld.run(|world| world.component_id::<Health>().unwrap());
+        entity.reflect_by_id(id).get_mut(|x| {
+            x.reflect_mut()
+                .as_struct()
+                .unwrap()
+                .field_mut("max")
+                .unwrap()
+                .apply(&3.0f32)
+        })?;
+        assert_eq!(entity.component::<Health>().get(|x| x.max)?, 3.0);
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
+
+#[test]
+pub fn reflect_errors() {
+    let mut app = AsyncTestApp::new();
+    app.register_type::<Health>();
+    app.register_type::<Frozen>();
+    let task = app.spawn(async {
+        let entity = AsyncWorld.spawn_bundle(Opaque);
+        assert_eq!(
+            entity
+                .reflect(String::from("not::a::Component"))
+                .get(|_| ()),
+            Err(AccessError::Custom(
+                "component not registered with ReflectComponent"
+            ))
+        );
+        let id = AsyncWorld.run(|world| world.component_id::<Opaque>().unwrap());
+        assert_eq!(
+            entity.reflect_by_id(id).remove(),
+            Err(AccessError::Custom(
+                "component not registered with ReflectComponent"
+            ))
+        );
+        let frozen = AsyncWorld.spawn_bundle(Frozen(1.0));
+        assert_eq!(frozen.reflect("reflect::Frozen").get(|_| ()), Ok(()));
+        assert_eq!(
+            frozen.reflect("reflect::Frozen").apply(&Frozen(2.0)),
+            Err(AccessError::ComponentNotFound {
+                entity: frozen.id(),
+                name: "reflect::Frozen"
+            })
+        );
+        entity.despawn();
+        assert_eq!(
+            entity.reflect(HEALTH).get(|_| ()),
+            Err(AccessError::EntityNotFound(entity.id()))
+        );
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
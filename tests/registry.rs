@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use bevy_defer::{AsyncExtension, AsyncPlugin, AsyncWorld, TaskOptions, TaskRegistry};
+
+#[test]
+pub fn registry_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.spawn_task(async move {
+        let task =
+            AsyncWorld.spawn_task_with(TaskOptions::named("sleeper").with_tag("test"), async {
+                AsyncWorld.sleep_frames(10).await;
+            });
+        AsyncWorld.spawn_any_with("yielder", async {
+            AsyncWorld.yield_now().await;
+        });
+        AsyncWorld.yield_now().await;
+        AsyncWorld.yield_now().await;
+        let registry = AsyncWorld.resource::<TaskRegistry>().get(|r| r.clone())?;
+        let sleeper = registry.named("sleeper");
+        assert_eq!(sleeper.len(), 1);
+        assert!(sleeper[0].has_tag("test"));
+        assert!(sleeper[0].polls > 0);
+        assert!(sleeper[0].last_polled_frame.is_some());
+        assert_eq!(sleeper[0].location.file(), file!());
+        assert_eq!(registry.tagged("test").len(), 1);
+        assert!(registry.named("yielder").is_empty());
+        task.await;
+        assert!(registry.named("sleeper").is_empty());
+        AsyncWorld.quit();
+        Ok(())
+    });
+    app.run();
+}
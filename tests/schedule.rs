@@ -0,0 +1,155 @@
+use bevy::diagnostic::FrameCount;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_defer::{AccessError, AccessResult, AsyncExtension, AsyncPlugin, AsyncWorld};
+use futures::StreamExt;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Debug, Default, Resource)]
+struct FixedSteps(u32);
+
+fn count_fixed_steps(mut steps: ResMut<FixedSteps>) {
+    steps.0 += 1;
+}
+
+#[test]
+pub fn fixed_tick_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(50)));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        125,
+    )));
+    app.init_resource::<FixedSteps>();
+    app.add_systems(FixedPreUpdate, count_fixed_steps);
+    let mismatches = Rc::new(Cell::new(0));
+    let ticks = Rc::new(Cell::new(0));
+    let m = mismatches.clone();
+    let t = ticks.clone();
+    app.spawn_task(async move {
+        loop {
+            AsyncWorld.next_fixed_tick().await;
+            t.set(t.get() + 1);
+            if AsyncWorld.resource::<FixedSteps>().get(|s| s.0)? != t.get() {
+                m.set(m.get() + 1);
+            }
+        }
+    });
+    for _ in 0..10 {
+        app.update();
+    }
+    let steps = app.world().resource::<FixedSteps>().0;
+    assert!(steps > 10);
+    assert_eq!(ticks.get(), steps);
+    assert_eq!(mismatches.get(), 0);
+}
+
+#[test]
+pub fn next_schedule_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings().with_schedule_hook(PostUpdate));
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        125,
+    )));
+    let frames = Rc::new(Cell::new(None));
+    let fixed = Rc::new(Cell::new(false));
+    let f = frames.clone();
+    let x = fixed.clone();
+    app.spawn_task(async move {
+        assert_eq!(
+            AsyncWorld.next_schedule(Last).await,
+            Err(AccessError::ScheduleNotFound)
+        );
+        let before = AsyncWorld.resource::<FrameCount>().get(|f| f.0)?;
+        AsyncWorld.next_schedule(PostUpdate).await?;
+        let after = AsyncWorld.resource::<FrameCount>().get(|f| f.0)?;
+        f.set(Some((before, after)));
+        // `FixedUpdate` always has a hook.
+        AsyncWorld.next_schedule(FixedUpdate).await?;
+        x.set(true);
+        Ok(())
+    });
+    app.update();
+    let (before, after) = frames.get().unwrap();
+    assert_eq!(before, after);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(fixed.get());
+}
+
+#[derive(Debug, Default, Resource)]
+struct InFixedUpdate(bool);
+
+#[test]
+pub fn schedule_hook_only_polls_waiting_tasks() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(50)));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        125,
+    )));
+    app.init_resource::<InFixedUpdate>();
+    app.add_systems(FixedPreUpdate, |mut x: ResMut<InFixedUpdate>| x.0 = true);
+    app.add_systems(FixedPostUpdate, |mut x: ResMut<InFixedUpdate>| x.0 = false);
+    let (sender, mut receiver) = futures::channel::mpsc::unbounded();
+    let ticks = Rc::new(Cell::new(0));
+    let received = Rc::new(Cell::new(0));
+    let in_fixed = Rc::new(Cell::new(0));
+    let t = ticks.clone();
+    app.spawn_task(async move {
+        loop {
+            AsyncWorld.next_fixed_tick().await;
+            assert!(AsyncWorld.resource::<InFixedUpdate>().get(|x| x.0)?);
+            t.set(t.get() + 1);
+            let _ = sender.unbounded_send(());
+        }
+    });
+    let r = received.clone();
+    let f = in_fixed.clone();
+    app.spawn_task(async move {
+        // Woken during `FixedUpdate`, but should only be polled by the executor.
+        while receiver.next().await.is_some() {
+            r.set(r.get() + 1);
+            if AsyncWorld.resource::<InFixedUpdate>().get(|x| x.0)? {
+                f.set(f.get() + 1);
+            }
+        }
+        Ok(())
+    });
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(ticks.get() > 10);
+    assert!(received.get() > 0);
+    assert_eq!(in_fixed.get(), 0);
+}
+
+#[test]
+pub fn next_schedule_polled_late() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings().with_schedule_hook(First));
+    app.add_plugins(MinimalPlugins);
+    let frames = Rc::new(Cell::new(None));
+    let f = frames.clone();
+    app.spawn_task(async move {
+        let next = AsyncWorld.next_schedule(First);
+        // `First` runs before the future is first polled.
+        AsyncWorld.yield_now().await;
+        let before = AsyncWorld.resource::<FrameCount>().get(|f| f.0)?;
+        next.await?;
+        let after = AsyncWorld.resource::<FrameCount>().get(|f| f.0)?;
+        f.set(Some((before, after)));
+        AccessResult::Ok(())
+    });
+    for _ in 0..3 {
+        app.update();
+    }
+    let (before, after) = frames.get().unwrap();
+    assert_eq!(after, before + 1);
+}
@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use bevy_defer::{AccessError, AsyncExtension, AsyncPlugin, AsyncWorld};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+#[test]
+pub fn scope_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.spawn_task(async move {
+        let values = RefCell::new(Vec::new());
+        let values_ref = &values;
+        let sum = AsyncWorld
+            .scope(|scope| async move {
+                let a = scope.spawn(async {
+                    AsyncWorld.sleep_frames(2).await;
+                    values_ref.borrow_mut().push(2);
+                    Ok(2)
+                });
+                scope.spawn(async {
+                    AsyncWorld.sleep_frames(4).await;
+                    values_ref.borrow_mut().push(4);
+                    Ok(())
+                });
+                let inner = scope.clone();
+                scope.spawn(async move {
+                    AsyncWorld.sleep_frames(1).await;
+                    inner.spawn(async {
+                        AsyncWorld.sleep_frames(6).await;
+                        values_ref.borrow_mut().push(6);
+                        Ok(())
+                    });
+                    Ok(())
+                });
+                Ok(a.await.unwrap() + 1)
+            })
+            .await?;
+        assert_eq!(sum, 3);
+        assert_eq!(values.into_inner(), vec![2, 4, 6]);
+        AsyncWorld.quit();
+        Ok(())
+    });
+    app.run();
+}
+
+#[test]
+pub fn scope_error_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.spawn_task(async move {
+        let completed = Cell::new(false);
+        let completed_ref = &completed;
+        let result = AsyncWorld
+            .scope(|scope| async move {
+                scope.spawn(async {
+                    AsyncWorld.sleep_frames(2).await;
+                    Err::<(), _>(AccessError::Custom("failed"))
+                });
+                scope.spawn(async {
+                    AsyncWorld.sleep_frames(10).await;
+                    completed_ref.set(true);
+                    Ok(())
+                });
+                Ok(())
+            })
+            .await;
+        assert_eq!(result, Err(AccessError::Custom("failed")));
+        AsyncWorld.sleep_frames(20).await;
+        assert!(!completed.get());
+        AsyncWorld.quit();
+        Ok(())
+    });
+    app.run();
+}
+
+fn child_panic() -> Result<(), AccessError> {
+    panic!("child panicked")
+}
+
+#[test]
+pub fn scope_panic_test() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let hook_reports = reports.clone();
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings().on_panic(move |panic| {
+        hook_reports.lock().unwrap().push(panic.message.clone());
+    }));
+    app.add_plugins(MinimalPlugins);
+    let completed = Rc::new(Cell::new(false));
+    let returned = Rc::new(Cell::new(false));
+    let (c, r) = (completed.clone(), returned.clone());
+    app.spawn_task(async move {
+        let _ = AsyncWorld
+            .scope(|scope| async move {
+                scope.spawn(async {
+                    AsyncWorld.sleep_frames(2).await;
+                    child_panic()
+                });
+                scope.spawn(async move {
+                    AsyncWorld.sleep_frames(4).await;
+                    c.set(true);
+                    Ok(())
+                });
+                Ok(())
+            })
+            .await;
+        r.set(true);
+        Ok(())
+    });
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(!completed.get());
+    assert!(!returned.get());
+    assert_eq!(
+        *reports.lock().unwrap(),
+        vec![Some("child panicked".to_owned())]
+    );
+}
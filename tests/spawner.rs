@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy_defer::{
+    AccessResult, AsyncExtension, AsyncPlugin, AsyncSpawner, AsyncWorld, TaskOptions,
+};
+
+#[derive(Debug, Clone, Copy, Component, PartialEq)]
+struct Int(i32);
+
+fn count_ints(app: &mut App) -> usize {
+    app.world_mut().query::<&Int>().iter(app.world()).count()
+}
+
+#[test]
+pub fn spawn_from_thread() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    let spawner = app.world().resource::<AsyncSpawner>().clone();
+    std::thread::spawn(move || {
+        for i in 0..3 {
+            spawner.spawn_with(
+                TaskOptions::default().with_name("remote"),
+                move || async move {
+                    AsyncWorld.sleep_frames(1).await;
+                    AsyncWorld.spawn_bundle(Int(i));
+                    AccessResult::Ok(())
+                },
+            );
+        }
+    })
+    .join()
+    .unwrap();
+    assert_eq!(count_ints(&mut app), 0);
+    app.update();
+    assert_eq!(count_ints(&mut app), 0);
+    app.update();
+    app.update();
+    assert_eq!(count_ints(&mut app), 3);
+}
+
+#[test]
+pub fn spawn_task_from_thread() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    let spawner = app.world().resource::<AsyncSpawner>().clone();
+    let handle = std::thread::spawn(move || {
+        futures::executor::block_on(spawner.spawn_task(|| async {
+            AsyncWorld.spawn_bundle(Int(1));
+            AsyncWorld.frame_count()
+        }))
+    });
+    while !handle.is_finished() {
+        app.update();
+    }
+    assert!(handle.join().unwrap().is_some());
+    assert_eq!(count_ints(&mut app), 1);
+}
+
+#[test]
+pub fn spawn_from_unblock() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.spawn_task(async {
+        let spawner = AsyncWorld.resource::<AsyncSpawner>().cloned()?;
+        AsyncWorld
+            .unblock(move || {
+                spawner.spawn(|| async {
+                    AsyncWorld.spawn_bundle(Int(2));
+                    AccessResult::Ok(())
+                })
+            })
+            .await;
+        Ok(())
+    });
+    for _ in 0..100 {
+        app.update();
+        if count_ints(&mut app) == 1 {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    panic!("remote task not spawned");
+}
+
+#[test]
+pub fn spawn_task_cancelled() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AsyncPlugin::default_settings());
+    let spawner = app.world().resource::<AsyncSpawner>().clone();
+    let output = spawner.spawn_task(|| async {
+        AsyncWorld.sleep_frames(10).await;
+    });
+    app.update();
+    drop(app);
+    assert_eq!(futures::executor::block_on(output), None);
+}
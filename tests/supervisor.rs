@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_defer::{AccessError, AsyncExtension, AsyncPlugin, AsyncWorld, RestartPolicy};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+fn failing(
+    runs: &Rc<Cell<usize>>,
+    succeed_on: usize,
+) -> impl FnMut() -> std::future::Ready<Result<(), AccessError>> {
+    let runs = runs.clone();
+    move || {
+        runs.set(runs.get() + 1);
+        if runs.get() >= succeed_on {
+            std::future::ready(Ok(()))
+        } else {
+            std::future::ready(Err(AccessError::Custom("failed")))
+        }
+    }
+}
+
+#[test]
+pub fn supervisor_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let runs = Rc::new(Cell::new(0));
+    let r = runs.clone();
+    app.spawn_task(async move {
+        // Gives up after 2 retries.
+        let result = AsyncWorld
+            .spawn_supervised(failing(&r, 10), RestartPolicy::OnError { max_retries: 2 })
+            .await;
+        assert_eq!(result, Err(AccessError::Custom("failed")));
+        assert_eq!(r.get(), 3);
+        // Succeeds on the 3rd run.
+        r.set(0);
+        let result = AsyncWorld
+            .spawn_supervised(failing(&r, 3), RestartPolicy::on_error())
+            .await;
+        assert_eq!(result, Ok(()));
+        assert_eq!(r.get(), 3);
+        AsyncWorld.quit();
+        Ok(())
+    });
+    app.run();
+    assert_eq!(runs.get(), 3);
+}
+
+#[test]
+pub fn supervisor_always_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let runs = Rc::new(Cell::new(0));
+    let r = runs.clone();
+    app.spawn_task(async move {
+        AsyncWorld
+            .spawn_supervised(
+                move || {
+                    r.set(r.get() + 1);
+                    async {
+                        AsyncWorld.yield_now().await;
+                        Ok(())
+                    }
+                },
+                RestartPolicy::Always,
+            )
+            .detach();
+        Ok(())
+    });
+    // Each run yields once, then waits a frame before restarting.
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(runs.get(), 3);
+}
+
+#[test]
+pub fn supervisor_ready_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let always = Rc::new(Cell::new(0));
+    let on_error = Rc::new(Cell::new(0));
+    let (a, e) = (always.clone(), on_error.clone());
+    app.spawn_task(async move {
+        AsyncWorld
+            .spawn_supervised(
+                move || {
+                    a.set(a.get() + 1);
+                    std::future::ready(Ok(()))
+                },
+                RestartPolicy::Always,
+            )
+            .detach();
+        AsyncWorld
+            .spawn_supervised(failing(&e, usize::MAX), RestartPolicy::on_error())
+            .detach();
+        Ok(())
+    });
+    // Futures that complete immediately are restarted once per frame.
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(always.get(), 5);
+    assert_eq!(on_error.get(), 5);
+}
+
+#[test]
+pub fn supervisor_backoff_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        125,
+    )));
+    let runs = Rc::new(Cell::new(0));
+    let r = runs.clone();
+    app.spawn_task(async move {
+        let policy = RestartPolicy::backoff(Duration::from_millis(125), Duration::from_millis(500))
+            .with_max_retries(4);
+        let result = AsyncWorld.spawn_supervised(failing(&r, 10), policy).await;
+        assert!(result.is_err());
+        Ok(())
+    });
+    let mut frames = Vec::new();
+    for frame in 0..20 {
+        let before = runs.get();
+        app.update();
+        if runs.get() != before {
+            frames.push(frame);
+        }
+    }
+    assert_eq!(runs.get(), 5);
+    // Delays of 1, 2, 4, 4 frames.
+    let gaps: Vec<_> = frames.windows(2).map(|w| w[1] - w[0]).collect();
+    assert_eq!(gaps, vec![1, 2, 4, 4]);
+}
+
+#[test]
+pub fn supervisor_zero_backoff_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let zero = Rc::new(Cell::new(0));
+    let capped = Rc::new(Cell::new(0));
+    let (z, c) = (zero.clone(), capped.clone());
+    app.spawn_task(async move {
+        AsyncWorld
+            .spawn_supervised(
+                failing(&z, usize::MAX),
+                RestartPolicy::backoff(Duration::ZERO, Duration::from_secs(1)),
+            )
+            .detach();
+        AsyncWorld
+            .spawn_supervised(
+                failing(&c, usize::MAX),
+                RestartPolicy::backoff(Duration::from_secs(1), Duration::ZERO),
+            )
+            .detach();
+        Ok(())
+    });
+    // Zero delays still restart once per frame.
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(zero.get(), 5);
+    assert_eq!(capped.get(), 5);
+}
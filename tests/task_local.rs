@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy_defer::{AsyncExtension, AsyncPlugin, AsyncWorld, TaskOptions};
+use std::sync::{Arc, Mutex};
+
+bevy_defer::task_local! {
+    static PLAYER: Entity;
+    static SPAN: &'static str;
+}
+
+fn log(out: &Mutex<Vec<String>>, message: &str) {
+    let span = SPAN.get().unwrap_or("none");
+    out.lock().unwrap().push(format!("[{span}] {message}"));
+}
+
+#[test]
+pub fn task_local_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let out = Arc::new(Mutex::new(Vec::new()));
+    let o = out.clone();
+    app.spawn_task(async move {
+        assert!(!PLAYER.is_set());
+        let player = AsyncWorld.spawn_bundle(()).id();
+        PLAYER.set(player);
+        SPAN.set("parent");
+        log(&o, "start");
+        let o2 = o.clone();
+        let inherited =
+            AsyncWorld.spawn_task_with(TaskOptions::new().inherit_locals(), async move {
+                AsyncWorld.yield_now().await;
+                log(&o2, "inherited");
+                // Changes are not visible to the parent.
+                SPAN.set("child");
+                PLAYER.get()
+            });
+        let o3 = o.clone();
+        let isolated = AsyncWorld.spawn_task(async move {
+            log(&o3, "isolated");
+            PLAYER.get()
+        });
+        assert_eq!(inherited.await, Some(player));
+        assert_eq!(isolated.await, None);
+        log(&o, "end");
+        assert_eq!(PLAYER.remove().as_deref(), Some(&player));
+        assert!(!PLAYER.is_set());
+        AsyncWorld.quit();
+        Ok(())
+    });
+    app.run();
+    assert_eq!(
+        *out.lock().unwrap(),
+        vec![
+            "[parent] start",
+            "[none] isolated",
+            "[parent] inherited",
+            "[parent] end"
+        ]
+    );
+}
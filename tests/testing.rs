@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_defer::testing::AsyncTestApp;
+use bevy_defer::{AsyncPlugin, AsyncWorld};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Component, PartialEq)]
+struct Int(i32);
+
+#[test]
+pub fn test_app_sleep() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        AsyncWorld.sleep(2.0).await;
+        AsyncWorld.now()
+    });
+    app.run_until_stalled();
+    app.assert_pending(&task);
+    app.advance(Duration::from_secs(1));
+    app.run_until_stalled();
+    app.assert_pending(&task);
+    app.advance(Duration::from_secs(1));
+    assert_eq!(app.assert_completed(task), Duration::from_secs(2));
+    assert_eq!(app.elapsed(), Duration::from_secs(2));
+}
+
+#[test]
+pub fn test_app_frames() {
+    let mut app = AsyncTestApp::new();
+    let start = app.frame_count();
+    let task = app.spawn(async {
+        AsyncWorld.sleep_frames(5).await;
+        AsyncWorld.frame_count()
+    });
+    // The first frame polls the task.
+    app.step_frames(5);
+    app.assert_pending(&task);
+    app.step();
+    assert_eq!(app.assert_completed(task), start + 5);
+}
+
+#[test]
+pub fn test_app_run_until_stalled() {
+    let mut app = AsyncTestApp::with_plugin(AsyncPlugin::default_settings());
+    let entity = app.world_mut().spawn(Int(0)).id();
+    let task = app.spawn(async move {
+        for i in 1..=3 {
+            AsyncWorld.sleep_frames(i).await;
+            AsyncWorld
+                .entity(entity)
+                .component::<Int>()
+                .get_mut(|x| x.0 += 1)?;
+        }
+        AsyncWorld
+            .entity(entity)
+            .component::<Int>()
+            .watch(|x| (x.0 == 4).then_some(()))
+            .await
+    });
+    let frames = app.run_until_stalled();
+    assert!(frames >= 6);
+    assert_eq!(app.world().get::<Int>(entity), Some(&Int(3)));
+    app.assert_pending(&task);
+    app.world_mut().get_mut::<Int>(entity).unwrap().0 = 4;
+    app.run_until_stalled();
+    assert_eq!(app.assert_completed(task), Ok(()));
+}
@@ -0,0 +1,124 @@
+use bevy_defer::testing::AsyncTestApp;
+use bevy_defer::{AccessError, AsyncWorld, Timeline};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+const STEP: Duration = Duration::from_millis(125);
+
+#[test]
+pub fn test_sleep_until_no_drift() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let start = AsyncWorld.now();
+        let mut beats = Vec::new();
+        for beat in 1..=4 {
+            AsyncWorld.sleep_until(start + STEP * 2 * beat).await;
+            beats.push(AsyncWorld.now() - start);
+            // Work between beats does not delay the next beat.
+            AsyncWorld.sleep_frames(1).await;
+        }
+        beats
+    });
+    app.step();
+    for _ in 0..8 {
+        app.advance(STEP);
+    }
+    app.step();
+    assert_eq!(
+        app.assert_completed(task),
+        vec![STEP * 2, STEP * 4, STEP * 6, STEP * 8]
+    );
+}
+
+#[test]
+pub fn test_sleep_until_passed() {
+    let mut app = AsyncTestApp::new();
+    app.advance(STEP);
+    let task = app.spawn(async {
+        AsyncWorld.sleep_until(Duration::ZERO).await;
+        AsyncWorld.sleep_until_frame(0).await;
+    });
+    app.step();
+    app.assert_completed(task);
+}
+
+#[test]
+pub fn test_sleep_until_frame() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let frame = AsyncWorld.frame_count() + 3;
+        AsyncWorld.sleep_until_frame(frame).await;
+        AsyncWorld.frame_count() - frame
+    });
+    app.run_until_stalled();
+    assert_eq!(app.assert_completed(task), 0);
+}
+
+#[test]
+pub fn test_timeline() {
+    let mut app = AsyncTestApp::new();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let log2 = log.clone();
+    let task = app.spawn(async move {
+        let start = AsyncWorld.now();
+        let log = &log2;
+        let record = move |name: &'static str| async move {
+            log.borrow_mut().push((name, AsyncWorld.now() - start));
+            Ok(())
+        };
+        Timeline::new()
+            .with_start(start)
+            .at(STEP * 4, move || record("c"))
+            .at(STEP, move || async move {
+                record("a").await?;
+                // A long callback does not delay later ones.
+                AsyncWorld.sleep(STEP * 8).await;
+                record("d").await
+            })
+            .at(STEP * 2, move || record("b"))
+            .run()
+            .await
+    });
+    app.step();
+    for _ in 0..10 {
+        app.advance(STEP);
+    }
+    app.assert_completed(task).unwrap();
+    assert_eq!(
+        *log.borrow(),
+        vec![
+            ("a", STEP),
+            ("b", STEP * 2),
+            ("c", STEP * 4),
+            ("d", STEP * 9)
+        ]
+    );
+}
+
+#[test]
+pub fn test_timeline_error() {
+    let mut app = AsyncTestApp::new();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let log2 = log.clone();
+    let task = app.spawn(async move {
+        let log = &log2;
+        Timeline::new()
+            .at(STEP, || async { Err(AccessError::Custom("missed cue")) })
+            .at(STEP * 2, move || async move {
+                log.borrow_mut().push(());
+                Ok(())
+            })
+            .run()
+            .await
+    });
+    app.step();
+    for _ in 0..4 {
+        app.advance(STEP);
+    }
+    assert_eq!(
+        app.assert_completed(task),
+        Err(AccessError::Custom("missed cue"))
+    );
+    assert!(log.borrow().is_empty());
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_defer::{
+    AccessError, AsyncExtension, AsyncPlugin, AsyncWorld, QueryQueue, TaskOptions, TimeoutExt,
+};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Component)]
+struct Answer(Option<u32>);
+
+#[test]
+pub fn timeout_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        125,
+    )));
+    let entity = app.world_mut().spawn(Answer(None)).id();
+    let done = Rc::new(Cell::new(false));
+    let d = done.clone();
+    app.spawn_task(async move {
+        let answer = || AsyncWorld.entity(entity).component::<Answer>();
+        // Nobody answers in time.
+        let result = answer().watch(|x| x.0).timeout(0.5).await;
+        assert_eq!(result, Err(AccessError::Timeout));
+        // Completes before the timeout.
+        AsyncWorld.sleep_frames(1).timeout_frames(2).await?;
+        assert_eq!(
+            AsyncWorld.sleep_frames(3).timeout_frames(2).await,
+            Err(AccessError::Timeout)
+        );
+        let at = AsyncWorld.now() + Duration::from_secs(1);
+        AsyncWorld.deadline(at, AsyncWorld.sleep(0.5)).await?;
+        assert_eq!(
+            AsyncWorld.deadline(at, AsyncWorld.sleep(1.0)).await,
+            Err(AccessError::Timeout)
+        );
+        // A deadline in the past times out immediately.
+        assert_eq!(
+            AsyncWorld
+                .deadline(Duration::ZERO, AsyncWorld.sleep(1.0))
+                .await,
+            Err(AccessError::Timeout)
+        );
+        answer().get_mut(|x| x.0 = Some(42))?;
+        assert_eq!(answer().watch(|x| x.0).timeout(0.5).await, Ok(Ok(42)));
+        d.set(true);
+        Ok(())
+    });
+    for _ in 0..40 {
+        app.update();
+    }
+    assert!(done.get());
+}
+
+#[test]
+pub fn deadline_paused_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        125,
+    )));
+    let result = Rc::new(Cell::new(None));
+    let r = result.clone();
+    app.spawn_task_with(TaskOptions::new().with_tag("gameplay"), async move {
+        let at = AsyncWorld.now() + Duration::from_millis(500);
+        r.set(Some(
+            AsyncWorld.deadline(at, std::future::pending::<()>()).await,
+        ));
+        Ok(())
+    });
+    app.update();
+    app.world().non_send::<QueryQueue>().pause_tag("gameplay");
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(result.get(), None);
+    // The deadline is absolute, so it has passed during the pause.
+    app.world().non_send::<QueryQueue>().resume_tag("gameplay");
+    app.update();
+    assert_eq!(result.get(), Some(Err(AccessError::Timeout)));
+}
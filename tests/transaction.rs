@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+use bevy_defer::testing::AsyncTestApp;
+use bevy_defer::{AccessError, AccessResult, AsyncWorld};
+
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+struct Health(f32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+struct Armor(f32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+struct Shield;
+
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+struct Mana(f32);
+
+#[test]
+pub fn transaction_multiple_components() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let entity = AsyncWorld.spawn_bundle((Health(10.0), Armor(2.0), Transform::default()));
+        entity.transaction::<(&mut Health, &mut Armor, &mut Transform), _>(
+            |(mut health, mut armor, mut transform)| {
+                health.0 -= 5.0 - armor.0;
+                transform.translation.y = health.0;
+                armor.0 = 0.0;
+            },
+        )?;
+        assert_eq!(entity.component::<Health>().get(|x| *x)?, Health(7.0));
+        assert_eq!(entity.component::<Armor>().get(|x| *x)?, Armor(0.0));
+        assert_eq!(
+            entity.component::<Transform>().get(|x| x.translation.y)?,
+            7.0
+        );
+        let (health, armor) = entity.transaction::<(&Health, &Armor), _>(|(h, a)| (*h, *a))?;
+        assert_eq!((health, armor), (Health(7.0), Armor(0.0)));
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
+
+#[test]
+pub fn transaction_all_or_nothing() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let entity = AsyncWorld.spawn_bundle((Health(10.0), Armor(2.0)));
+        AsyncWorld.run(|w| w.register_component::<Shield>());
+        let result = entity.transaction::<(&mut Health, &mut Armor, &Shield), _>(
+            |(mut health, mut armor, _)| {
+                health.0 = 0.0;
+                armor.0 = 0.0;
+            },
+        );
+        assert_eq!(
+            result,
+            Err(AccessError::QueryConditionNotMet {
+                entity: entity.id(),
+                query: std::any::type_name::<(&mut Health, &mut Armor, &Shield)>()
+            })
+        );
+        assert_eq!(entity.component::<Health>().get(|x| *x)?, Health(10.0));
+        assert_eq!(entity.component::<Armor>().get(|x| *x)?, Armor(2.0));
+        let shield = AsyncWorld.run(|w| w.component_id::<Shield>()).unwrap();
+        let transform = AsyncWorld.run(|w| w.register_component::<Transform>());
+        let mut missing =
+            entity.missing_components::<(&mut Health, &Shield, Option<&Armor>, &Transform)>()?;
+        missing.sort();
+        assert_eq!(missing, {
+            let mut expected = vec![shield, transform];
+            expected.sort();
+            expected
+        });
+        assert_eq!(entity.missing_components::<(&Health, &Armor)>()?, vec![]);
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
+
+#[test]
+pub fn transaction_unregistered_components() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let entity = AsyncWorld.spawn_bundle(Health(10.0));
+        let result = entity.transaction::<(&mut Health, &Mana), _>(|(mut health, _)| {
+            health.0 = 0.0;
+        });
+        let query = std::any::type_name::<(&mut Health, &Mana)>();
+        assert_eq!(
+            result,
+            Err(AccessError::QueryConditionNotMet {
+                entity: entity.id(),
+                query
+            })
+        );
+        assert_eq!(
+            entity.missing_components::<(&mut Health, &Mana)>(),
+            Err(AccessError::QueryConditionNotMet {
+                entity: entity.id(),
+                query
+            })
+        );
+        // Failed reads do not register components.
+        assert_eq!(AsyncWorld.run(|w| w.component_id::<Mana>()), None);
+        assert_eq!(entity.component::<Health>().get(|x| *x)?, Health(10.0));
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
+
+#[test]
+pub fn transaction_virtual_entity() {
+    let mut app = AsyncTestApp::new();
+    let task = app.spawn(async {
+        let parent = AsyncWorld.spawn_bundle(Name::new("parent"));
+        let child = AsyncWorld.spawn_bundle(Health(1.0));
+        parent.add_child(child.id())?;
+        let virtual_child = parent.child(0);
+        virtual_child.transaction::<&mut Health, _>(|mut health| health.0 = 2.0)?;
+        assert_eq!(child.component::<Health>().get(|x| *x)?, Health(2.0));
+        child.despawn();
+        assert_eq!(
+            child.transaction::<(), _>(|_| ()),
+            Err(AccessError::EntityNotFound(child.id()))
+        );
+        AccessResult::Ok(())
+    });
+    app.step();
+    app.assert_completed(task).unwrap();
+}
@@ -18,3 +18,75 @@ pub fn unblock_test() {
     });
     app.run();
 }
+
+#[test]
+pub fn unblock_with_progress() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.spawn_task(async move {
+        let job = AsyncWorld.unblock_with(|ctx| {
+            for i in 1..=4 {
+                ctx.report_progress(i as f32 / 4.0);
+            }
+            "done"
+        });
+        let progress = job.progress();
+        assert_eq!(job.await, "done");
+        assert_eq!(progress.read(), Some(1.0));
+        AsyncWorld.quit();
+        Ok(())
+    });
+    app.run();
+}
+
+#[test]
+pub fn unblock_with_cancel_on_drop() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    app.spawn_task(async move {
+        let job = AsyncWorld.unblock_with(move |ctx| {
+            while !ctx.is_cancelled() {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+            sender.send("cancelled").unwrap();
+        });
+        // Let the operation start before dropping it.
+        AsyncWorld.sleep_frames(2).await;
+        drop(job);
+        Ok(())
+    });
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(
+        receiver.recv_timeout(std::time::Duration::from_secs(5)),
+        Ok("cancelled")
+    );
+}
+
+#[test]
+pub fn unblock_with_cancel() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    app.spawn_task(async move {
+        let job = AsyncWorld.unblock_with(|ctx| {
+            let mut steps = 0;
+            while !ctx.is_cancelled() {
+                steps += 1;
+                ctx.report_progress(steps as f32);
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+            steps
+        });
+        job.progress().read_async().await;
+        job.cancel();
+        assert!(job.await > 0);
+        AsyncWorld.quit();
+        Ok(())
+    });
+    app.run();
+}
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use bevy_defer::{AccessError, AsyncExtension, AsyncPlugin, AsyncWorld};
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[derive(Debug, Component)]
+struct Health(u32);
+
+#[test]
+pub fn watch_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let entity = app.world_mut().spawn_empty().id();
+    let added = Rc::new(Cell::new(false));
+    let changed = Rc::new(Cell::new(false));
+    let removed = Rc::new(Cell::new(false));
+    let despawned = Rc::new(Cell::new(None));
+    let (a, c, r, d) = (
+        added.clone(),
+        changed.clone(),
+        removed.clone(),
+        despawned.clone(),
+    );
+    app.spawn_task(async move {
+        let entity = AsyncWorld.entity(entity);
+        entity.watch_added::<Health>().await?;
+        a.set(true);
+        entity.watch_changed::<Health>().await?;
+        c.set(true);
+        entity.watch_removed::<Health>().await;
+        r.set(true);
+        d.set(Some(entity.watch_changed::<Name>().await));
+        Ok(())
+    });
+    app.update();
+    assert!(!added.get());
+    app.world_mut().entity_mut(entity).insert(Health(10));
+    app.update();
+    assert!(added.get());
+    app.update();
+    assert!(!changed.get());
+    app.world_mut().get_mut::<Health>(entity).unwrap().0 = 5;
+    app.update();
+    assert!(changed.get());
+    assert!(!removed.get());
+    app.world_mut().entity_mut(entity).remove::<Health>();
+    app.update();
+    assert!(removed.get());
+    app.update();
+    assert_eq!(despawned.get(), None);
+    app.world_mut().despawn(entity);
+    app.update();
+    assert_eq!(
+        despawned.get(),
+        Some(Err(AccessError::EntityNotFound(entity)))
+    );
+}
+
+#[test]
+pub fn watch_added_despawn_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let entity = app.world_mut().spawn_empty().id();
+    let result = Rc::new(Cell::new(None));
+    let r = result.clone();
+    app.spawn_task(async move {
+        r.set(Some(
+            AsyncWorld.entity(entity).watch_added::<Health>().await,
+        ));
+        Ok(())
+    });
+    app.update();
+    assert_eq!(result.get(), None);
+    app.world_mut().despawn(entity);
+    app.update();
+    assert_eq!(result.get(), Some(Err(AccessError::EntityNotFound(entity))));
+}
+
+#[test]
+pub fn watch_changed_since_test() {
+    let mut app = App::new();
+    app.add_plugins(AsyncPlugin::default_settings());
+    app.add_plugins(MinimalPlugins);
+    let entity = app.world_mut().spawn(Health(10)).id();
+    let first = Rc::new(Cell::new(false));
+    let second = Rc::new(Cell::new(false));
+    let (f, s) = (first.clone(), second.clone());
+    app.spawn_task(async move {
+        let entity = AsyncWorld.entity(entity);
+        let changed = entity.watch_changed::<Health>();
+        entity.component::<Health>().get_mut(|x| x.0 = 5)?;
+        let changed_later = entity.watch_changed::<Health>();
+        changed.await?;
+        f.set(true);
+        changed_later.await?;
+        s.set(true);
+        Ok(())
+    });
+    app.update();
+    assert!(first.get());
+    // Woken by the change made before `changed_later` was created, which it ignores.
+    app.update();
+    app.update();
+    assert!(!second.get());
+    app.world_mut().get_mut::<Health>(entity).unwrap().0 = 1;
+    app.update();
+    assert!(second.get());
+}